@@ -6,6 +6,31 @@ use std::f64::consts::PI;
 pub const COMMUNICATION_RANGE: i32 = 2; // Manhattan distance for robot communication
 pub const INITIAL_SCOUT_DEPTH: u32 = 3; // Initial boundary scouting depth
 
+/// Line-of-sight sensor radius (in cells) used by recursive shadowcasting to
+/// limit what a robot can actually see, rather than reading the global map.
+pub const SENSOR_RADIUS: i32 = 6;
+
+/// Maximum range (in cells) of the forward-looking DDA ray-fan sensor, used
+/// to look further down a corridor than the omnidirectional shadowcast so
+/// wall-following/sweeping phases can see an obstacle coming instead of
+/// bumping into it.
+pub const RAY_SENSOR_RANGE: i32 = 12;
+/// Angular width (radians) of the forward ray fan, centered on the robot's
+/// current `orientation_rad`.
+pub const RAY_FAN_WIDTH_RAD: f64 = PI / 2.0;
+/// Number of rays cast across `RAY_FAN_WIDTH_RAD` each time the fan fires.
+pub const RAY_FAN_COUNT: usize = 5;
+
+/// Range (in cells) of the single forward ray `WallFindPhase` casts each
+/// tick along its current heading, so initial wall-finding marks a stretch
+/// of cells `Empty` ahead of the robot instead of discovering the map one
+/// step at a time.
+pub const SENSOR_RANGE: i32 = 8;
+
+/// Movement bias during InitialWallFind: probability of repeating the previous
+/// step direction instead of re-choosing, to reduce zig-zag jitter on open maps.
+pub const DEFAULT_MOMENTUM_PROB: f64 = 0.8;
+
 /// Rotation and orientation constants
 pub const ROTATION_TOLERANCE: f64 = 0.5; // Tolerance for rotation-based analysis
 pub const EXPECTED_ROTATION_DIFFERENCE: i32 = 4; // Expected 90-degree step difference for boundary analysis
@@ -22,6 +47,19 @@ pub const SOUTH: (i32, i32) = (0, 1);
 pub const EAST: (i32, i32) = (1, 0);
 pub const WEST: (i32, i32) = (-1, 0);
 
+/// The eight neighbor offsets in rotational order (clockwise from North),
+/// for `Connectivity::Eight` movement.
+pub const EIGHT_NEIGHBORS: [(i32, i32); 8] = [
+    (0, -1),  // North
+    (1, -1),  // Northeast
+    (1, 0),   // East
+    (1, 1),   // Southeast
+    (0, 1),   // South
+    (-1, 1),  // Southwest
+    (-1, 0),  // West
+    (-1, -1), // Northwest
+];
+
 /// Cardinal directions for orientation mapping
 pub const ORIENTATION_STEPS: [(f64, i32); 4] = [
     (EAST_RAD, 0),   // East = 0 steps
@@ -40,4 +78,28 @@ pub const ROBOT_RIGHT_HAND: u8 = 1; // Robot using right-hand wall following
 
 /// Tracing directions
 pub const LEFT_HAND_RULE: i8 = -1;  // Counterclockwise tracing
-pub const RIGHT_HAND_RULE: i8 = 1;  // Clockwise tracing
\ No newline at end of file
+pub const RIGHT_HAND_RULE: i8 = 1;  // Clockwise tracing
+
+/// Frontier cluster goal scoring weights, 1:1 between cluster size and path
+/// cost. Nothing in the crate reads them: frontier allocation ranks
+/// candidates by A* path length alone.
+pub const FRONTIER_GAIN_WEIGHT: f64 = 1.0;
+pub const FRONTIER_DIST_WEIGHT: f64 = 1.0;
+
+/// Extra per-step A* cost near walls: `clearance_weight / (1 +
+/// clearance[cell])`, where `clearance` comes from `GridMap::distance_transform`.
+/// Hugging a wall (clearance 1) costs an extra `CLEARANCE_WEIGHT / 2`; deep open
+/// space (large clearance) costs almost nothing extra. Set to 0 to recover
+/// pure shortest-path A*.
+pub const CLEARANCE_WEIGHT: f64 = 1.0;
+
+/// Maximum number of entries kept in `RobotState::phase_history` before the
+/// oldest are dropped, so a long-running simulation's phase timeline stays
+/// bounded instead of growing for the life of the run.
+pub const PHASE_HISTORY_LIMIT: usize = 64;
+
+/// Default amount of stigmergic trail a robot deposits on its own cell each
+/// tick, and the multiplicative decay applied to the whole trail layer once
+/// per tick. See `SimulationManager::pheromone`.
+pub const PHEROMONE_DEPOSIT: f32 = 1.0;
+pub const PHEROMONE_DECAY: f32 = 0.95;
\ No newline at end of file
@@ -0,0 +1,53 @@
+/// Continuous-space geometry helpers for inter-robot proximity and collision
+/// checks, modeled on physics-engine closest-point queries. Complements the
+/// per-cell Manhattan tests (`within_comm_range`, grid adjacency) used
+/// elsewhere with true Euclidean distance, so two robots are never treated as
+/// dimensionless points that may occupy or pass through the same cell.
+
+use crate::types::{Point, Pose};
+
+/// Half-width each robot occupies around its center, in cells. Two robots
+/// collide when their center-to-center distance drops below `2.0 * ROBOT_RADIUS`.
+pub const ROBOT_RADIUS: f64 = 0.4;
+
+/// True Euclidean center-to-center distance between two poses.
+pub fn closest_distance(a_pose: Pose, b_pose: Pose) -> f64 {
+    let dx = (a_pose.position.x - b_pose.position.x) as f64;
+    let dy = (a_pose.position.y - b_pose.position.y) as f64;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// Minimum distance between robot A (moving `a_prev` -> `a_next` over the tick)
+/// and robot B (moving `b_prev` -> `b_next` over the same tick), assuming both
+/// move at a constant rate across `t in [0, 1]`. Unlike a static
+/// segment-to-segment distance, this accounts for both robots moving
+/// simultaneously, so a fast pass-through that never coincides at either
+/// endpoint is still caught.
+pub fn segment_closest_distance(a_prev: Point, a_next: Point, b_prev: Point, b_next: Point) -> f64 {
+    let r0 = ((a_prev.x - b_prev.x) as f64, (a_prev.y - b_prev.y) as f64);
+    let va = ((a_next.x - a_prev.x) as f64, (a_next.y - a_prev.y) as f64);
+    let vb = ((b_next.x - b_prev.x) as f64, (b_next.y - b_prev.y) as f64);
+    let v = (va.0 - vb.0, va.1 - vb.1);
+
+    // |r0 + t*v|^2 is a quadratic in t; minimize it analytically and clamp to
+    // the valid range since both robots only move within the tick.
+    let a = v.0 * v.0 + v.1 * v.1;
+    let t = if a < f64::EPSILON {
+        0.0
+    } else {
+        let b = 2.0 * (r0.0 * v.0 + r0.1 * v.1);
+        (-b / (2.0 * a)).clamp(0.0, 1.0)
+    };
+
+    let dx = r0.0 + t * v.0;
+    let dy = r0.1 + t * v.1;
+    (dx * dx + dy * dy).sqrt()
+}
+
+/// True if a robot centered at `a` and one centered at `b` would overlap given
+/// each robot's `ROBOT_RADIUS`.
+pub fn robots_would_collide(a: Point, b: Point) -> bool {
+    let dx = (a.x - b.x) as f64;
+    let dy = (a.y - b.y) as f64;
+    (dx * dx + dy * dy).sqrt() < 2.0 * ROBOT_RADIUS
+}
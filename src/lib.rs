@@ -4,4 +4,9 @@ pub mod robot_node;
 pub mod map_manager;
 pub mod path_planner;
 pub mod simulation_manager;
-pub mod map_loader; 
\ No newline at end of file
+pub mod map_loader;
+pub mod map_generator;
+pub mod svg_export;
+pub mod logging;
+pub mod geometry;
+pub mod world;
@@ -0,0 +1,58 @@
+/// CSV logging of a completed run, for analyzing boundary-scouting convergence
+/// offline instead of scrolling back through the `println!` stream.
+
+use crate::robot_node::RobotNode;
+use crate::types::CellState;
+use std::fs::File;
+use std::io::{self, Write};
+
+/// Writes one CSV row per (tick, robot): position, orientation, phase, and the
+/// boundary-scouting progress fields (`scout_depth_n`, `steps_taken`,
+/// `total_rotation_steps`; the last two are 0 when the robot has no
+/// `boundary_scout` state).
+pub fn dump_trajectories(history: &[Vec<RobotNode>], path: &str) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "tick,robot_id,x,y,orientation_rad,phase,scout_depth_n,steps_taken,total_rotation_steps")?;
+
+    for (tick, snapshot) in history.iter().enumerate() {
+        for robot in snapshot {
+            let state = &robot.state;
+            let (steps_taken, total_rotation_steps) = state.boundary_scout
+                .as_ref()
+                .map(|s| (s.steps_taken, s.total_rotation_steps))
+                .unwrap_or((0, 0));
+
+            writeln!(
+                file,
+                "{},{},{},{},{},{:?},{},{},{}",
+                tick,
+                state.id,
+                state.pose.position.x,
+                state.pose.position.y,
+                state.pose.orientation_rad,
+                state.phase,
+                state.scout_depth_n,
+                steps_taken,
+                total_rotation_steps,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes one CSV row per (tick, robot) summarizing the explored-cell count
+/// (non-`Unexplored` cells) in that robot's own map, so coverage growth over
+/// time can be plotted and compared across maps.
+pub fn dump_coverage(history: &[Vec<RobotNode>], path: &str) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    writeln!(file, "tick,robot_id,explored_cells,total_cells")?;
+
+    for (tick, snapshot) in history.iter().enumerate() {
+        for robot in snapshot {
+            let map = &robot.state.map;
+            let explored = map.cells.iter().filter(|&&cell| cell != CellState::Unexplored).count();
+            writeln!(file, "{},{},{},{}", tick, robot.state.id, explored, map.cells.len())?;
+        }
+    }
+    Ok(())
+}
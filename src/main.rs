@@ -53,7 +53,7 @@ fn main() {
         for robot in &mut sim.robots {
             for other in &robots_snapshot {
                 if other.state.id != robot.state.id && RobotNode::within_comm_range(&robot.state.pose.position, &other.state.pose.position) {
-                    robot.merge_map(&other.state.map);
+                    robot.merge_map(&other.state.map, other.state.pose);
                 }
             }
         }
@@ -65,17 +65,13 @@ fn main() {
         let robots_snapshot = sim.robots.clone();
         let mut all_robots_completed = true;
         for robot in &mut sim.robots {
-            let completed = robot.tick(&robots_snapshot, &sim.map);
-            if completed {
-                println!("*** DEBUG: Robot {} returned completion status at tick {}", robot.state.id, tick);
-            }
+            let completed = robot.tick(&robots_snapshot, &sim.map, &mut sim.pheromone, sim.pheromone_deposit);
             if !completed {
                 all_robots_completed = false;
             }
         }
-        
-        if all_robots_completed {
-            println!("*** DEBUG: All robots completed at tick {}", tick);
+        for cell in &mut sim.pheromone {
+            *cell *= sim.pheromone_decay;
         }
 
         // Loop closure detection is now handled within individual robot logic
@@ -91,18 +87,7 @@ fn main() {
         let both_robots_idle = sim.robots.iter().all(|r|
             r.state.phase == RobotPhase::Idle
         );
-        
-        if both_robots_idle {
-            println!("*** DEBUG: Both robots are in Idle phase at tick {}", tick);
-        }
-        
-        // Check current robot phases every tick after 150
-        if tick >= 150 {
-            for robot in &sim.robots {
-                println!("*** DEBUG: Tick {}: Robot {} in phase {:?}", tick, robot.state.id, robot.state.phase);
-            }
-        }
-        
+
         if both_robots_idle {
             println!("Exploration complete - both robots in Idle phase! Final maps:");
             sim.print_all_maps();
@@ -137,6 +122,14 @@ fn main() {
             break;
         }
     }
+    println!("=== Phase timelines ===");
+    for robot in &sim.robots {
+        println!("Robot {}:", robot.state.id);
+        for (tick, transition) in &robot.state.phase_history {
+            println!("  tick {}: {:?} -> {:?} ({})", tick, transition.from, transition.to, transition.label);
+        }
+    }
+
     println!("Simulation complete. Launching visualization...");
     let map_width = sim.map.width;
     let map_height = sim.map.height;
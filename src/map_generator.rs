@@ -0,0 +1,172 @@
+/// Procedural generation of random but solvable `GridMap`s, for batch benchmarking
+/// of the scouting algorithm without hand-authoring a map file per run.
+
+use crate::types::{CellState, GridMap};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use std::collections::VecDeque;
+
+/// Carves a maze with the recursive-backtracker algorithm: cells sit on odd
+/// coordinates, walls on even ones, and carving knocks out the wall between
+/// the current cell and an unvisited neighbor two cells away. This guarantees
+/// the carved cells form a single connected tree, so every `Empty` cell is
+/// reachable from every other.
+///
+/// `width`/`height` are rounded up to the next odd number so the cell/wall
+/// grid lines up; the border stays `Obstacle`.
+pub fn generate_maze(width: usize, height: usize, seed: u64) -> GridMap {
+    let width = width | 1;
+    let height = height | 1;
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut cells = vec![CellState::Obstacle; width * height];
+
+    let cols = (width - 1) / 2;
+    let rows = (height - 1) / 2;
+    let mut visited = vec![false; cols * rows];
+
+    let to_grid = |col: usize, row: usize| (2 * col + 1, 2 * row + 1);
+    let idx = |x: usize, y: usize| y * width + x;
+
+    let mut stack = vec![(0usize, 0usize)];
+    visited[0] = true;
+    let (sx, sy) = to_grid(0, 0);
+    cells[idx(sx, sy)] = CellState::Empty;
+
+    while let Some(&(col, row)) = stack.last() {
+        // (neighbor cell col/row, wall coords carved between current and neighbor)
+        let mut neighbors: Vec<(usize, usize, usize, usize)> = Vec::new();
+        let candidates: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        for (dc, dr) in candidates {
+            let nc = col as i32 + dc;
+            let nr = row as i32 + dr;
+            if nc < 0 || nr < 0 || nc as usize >= cols || nr as usize >= rows {
+                continue;
+            }
+            let (nc, nr) = (nc as usize, nr as usize);
+            if visited[nr * cols + nc] {
+                continue;
+            }
+            let (cx, cy) = to_grid(col, row);
+            let wall = ((cx as i32 + dc) as usize, (cy as i32 + dr) as usize);
+            neighbors.push((nc, nr, wall.0, wall.1));
+        }
+
+        if neighbors.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (nc, nr, wx, wy) = neighbors[rng.gen_range(0..neighbors.len())];
+        cells[idx(wx, wy)] = CellState::Empty;
+        let (gx, gy) = to_grid(nc, nr);
+        cells[idx(gx, gy)] = CellState::Empty;
+        visited[nr * cols + nc] = true;
+        stack.push((nc, nr));
+    }
+
+    GridMap::new(width, height, cells)
+}
+
+/// Generates a cave-like map: random fill at `fill_prob`, smoothed by
+/// `smoothing_iterations` passes of the standard 4-5 cellular-automata rule
+/// (a cell becomes `Obstacle` if 5+ of its 8 neighbors are `Obstacle`,
+/// `Empty` otherwise), then keeps only the largest connected `Empty` region so
+/// robots never spawn into a sealed-off pocket.
+pub fn generate_cave(width: usize, height: usize, seed: u64, fill_prob: f64, smoothing_iterations: u32) -> GridMap {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut cells: Vec<CellState> = (0..width * height)
+        .map(|i| {
+            let (x, y) = (i % width, i / width);
+            if x == 0 || y == 0 || x == width - 1 || y == height - 1 || rng.gen_bool(fill_prob) {
+                CellState::Obstacle
+            } else {
+                CellState::Empty
+            }
+        })
+        .collect();
+
+    for _ in 0..smoothing_iterations {
+        cells = smooth_once(&cells, width, height);
+    }
+
+    keep_largest_empty_region(&mut cells, width, height);
+
+    GridMap::new(width, height, cells)
+}
+
+fn smooth_once(cells: &[CellState], width: usize, height: usize) -> Vec<CellState> {
+    let mut next = cells.to_vec();
+    for y in 0..height {
+        for x in 0..width {
+            let obstacle_neighbors = count_obstacle_neighbors(cells, width, height, x, y);
+            let idx = y * width + x;
+            next[idx] = if obstacle_neighbors >= 5 { CellState::Obstacle } else { CellState::Empty };
+        }
+    }
+    next
+}
+
+fn count_obstacle_neighbors(cells: &[CellState], width: usize, height: usize, x: usize, y: usize) -> u32 {
+    let mut count = 0;
+    for dy in -1i32..=1 {
+        for dx in -1i32..=1 {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+            let out_of_bounds = nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32;
+            if out_of_bounds || cells[ny as usize * width + nx as usize] == CellState::Obstacle {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Flood-fills every `Empty` region, keeps the largest, and flips the rest to
+/// `Obstacle` so the generated map has a single connected open area.
+fn keep_largest_empty_region(cells: &mut [CellState], width: usize, height: usize) {
+    let mut labels = vec![usize::MAX; cells.len()];
+    let mut region_sizes = Vec::new();
+
+    for start in 0..cells.len() {
+        if cells[start] != CellState::Empty || labels[start] != usize::MAX {
+            continue;
+        }
+        let region_id = region_sizes.len();
+        let mut size = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        labels[start] = region_id;
+
+        while let Some(pos) = queue.pop_front() {
+            size += 1;
+            let (x, y) = (pos % width, pos / width);
+            let neighbors: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+            for (dx, dy) in neighbors {
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                    continue;
+                }
+                let nidx = ny as usize * width + nx as usize;
+                if cells[nidx] == CellState::Empty && labels[nidx] == usize::MAX {
+                    labels[nidx] = region_id;
+                    queue.push_back(nidx);
+                }
+            }
+        }
+
+        region_sizes.push(size);
+    }
+
+    let largest_region = region_sizes
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, &size)| size)
+        .map(|(id, _)| id);
+
+    for (i, cell) in cells.iter_mut().enumerate() {
+        if *cell == CellState::Empty && Some(labels[i]) != largest_region {
+            *cell = CellState::Obstacle;
+        }
+    }
+}
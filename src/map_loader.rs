@@ -6,7 +6,7 @@ use crate::types::{GridMap, CellState};
 ///
 /// # Format
 /// - Each line is a row in the grid.
-/// - '#' = Obstacle, '.' = Empty, ' ' or '?' = Unexplored
+/// - '#' = Obstacle, '.' = Empty, ' ' or '?' = Unexplored, 'O' = Goal
 /// - All lines must have the same length.
 ///
 /// Returns a GridMap or an io::Error.
@@ -29,10 +29,11 @@ pub fn load_map_from_file(path: &str) -> io::Result<GridMap> {
                 '#' => CellState::Obstacle,
                 '.' => CellState::Empty,
                 ' ' | '?' => CellState::Unexplored,
+                'O' => CellState::Goal,
                 _ => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("Invalid map character: {}", ch))),
             };
             cells.push(cell);
         }
     }
-    Ok(GridMap { width, height, cells })
+    Ok(GridMap::new(width, height, cells))
 } 
\ No newline at end of file
@@ -1,18 +1,446 @@
 use crate::types::*;
+use std::collections::{HashSet, VecDeque};
+use std::f64::consts::PI;
 
 /// Map management and analysis utilities.
 pub mod map_manager {
     use super::*;
 
-    /// Determines if a completed loop is an island or an outer boundary.
+    /// Minimum number of cells a frontier cluster must have to be acted on;
+    /// smaller clusters are treated as sensor noise and discarded.
+    const MIN_FRONTIER_CLUSTER_SIZE: usize = 3;
+
+    /// Eight-connected neighbor offsets, used for frontier adjacency and clustering.
+    const EIGHT_NEIGHBORS: [(i32, i32); 8] = [
+        (-1, -1), (0, -1), (1, -1),
+        (-1, 0),           (1, 0),
+        (-1, 1),  (0, 1),  (1, 1),
+    ];
+
+    /// Four-connected neighbor offsets, used for the flood fill.
+    const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+    /// Determines if a completed loop is an island (interior obstacle) or the
+    /// map's outer boundary.
+    ///
+    /// Treats `loop_path` as a closed barrier and flood-fills every non-obstacle,
+    /// non-barrier cell reachable from the map's outer edge. If the loop itself
+    /// touches the outer edge, or the exterior flood leaks to a cell immediately
+    /// inside the loop, the loop is the exterior wall; otherwise it fully encloses
+    /// a region and is an island. This is robust to concave obstacles and partial
+    /// loops that a simple "does the path touch the border" check misclassifies.
     pub fn is_loop_an_island(map: &GridMap, loop_path: &[Point]) -> bool {
-        // TODO: Implement
-        false
+        let barrier: HashSet<Point> = loop_path.iter().copied().collect();
+        if barrier.iter().any(|&p| is_on_map_edge(map, p) && !is_portal_boundary_cell(map, p)) {
+            return false;
+        }
+
+        let mut visited: Grid<bool> = Grid::new(map.width, map.height, vec![false; map.width * map.height]);
+        let mut queue = VecDeque::new();
+
+        for (pos, &cell) in map.iter_coords() {
+            // A portal-seam edge cell isn't actually the world boundary -- it
+            // continues into another stitched-together region -- so it isn't a
+            // valid seed for "the true exterior".
+            if is_on_map_edge(map, pos) && !is_portal_boundary_cell(map, pos)
+                && cell != CellState::Obstacle && !barrier.contains(&pos) {
+                if visited.set(pos, true) {
+                    queue.push_back(pos);
+                }
+            }
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if barrier.contains(&neighbor) {
+                    continue;
+                }
+                let Some(&cell) = map.get(neighbor) else { continue };
+                if cell == CellState::Obstacle {
+                    continue;
+                }
+                if visited.get(neighbor) == Some(&false) {
+                    visited.set(neighbor, true);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        // A cell is just inside the loop when a ray cast from it toward +x
+        // crosses the loop's edges an odd number of times.
+        let is_inside = |p: Point| {
+            let n = loop_path.len();
+            (0..n).filter(|&i| {
+                let (a, b) = (loop_path[i], loop_path[(i + 1) % n]);
+                (a.y > p.y) != (b.y > p.y)
+                    && (p.x as f64) < a.x as f64 + (p.y - a.y) as f64 * (b.x - a.x) as f64 / (b.y - a.y) as f64
+            }).count() % 2 == 1
+        };
+
+        // If the exterior flood reached any cell just inside the loop, the loop
+        // doesn't actually enclose anything -- it's the outer boundary itself.
+        for &pos in &barrier {
+            for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if barrier.contains(&neighbor) {
+                    continue;
+                }
+                if visited.get(neighbor) == Some(&true) && is_inside(neighbor) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Counts the cells a closed loop encloses, for distinguishing a tiny
+    /// pillar island from a large interior courtyard once `is_loop_an_island`
+    /// has already confirmed the loop is an island.
+    ///
+    /// Mirrors `is_loop_an_island`'s exterior flood fill, but flips the
+    /// question: instead of asking whether the exterior reaches just inside
+    /// the loop, it floods from any non-barrier cell adjacent to the loop and
+    /// counts everywhere that flood reaches. Callers are expected to have
+    /// already ruled out the exterior-wall case; called on a non-enclosing
+    /// loop this simply counts whatever the flood from an arbitrary interior
+    /// seed reaches, which is not a meaningful count.
+    pub fn count_enclosed_cells(map: &GridMap, loop_path: &[Point]) -> usize {
+        let barrier: HashSet<Point> = loop_path.iter().copied().collect();
+
+        let seed = barrier.iter().find_map(|&pos| {
+            FOUR_NEIGHBORS.iter().find_map(|&(dx, dy)| {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if barrier.contains(&neighbor) {
+                    return None;
+                }
+                match map.get(neighbor) {
+                    Some(&cell) if cell != CellState::Obstacle => Some(neighbor),
+                    _ => None,
+                }
+            })
+        });
+
+        let Some(seed) = seed else { return 0 };
+
+        let mut visited: Grid<bool> = Grid::new(map.width, map.height, vec![false; map.width * map.height]);
+        let mut queue = VecDeque::new();
+        visited.set(seed, true);
+        queue.push_back(seed);
+        let mut count = 1;
+
+        while let Some(pos) = queue.pop_front() {
+            for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if barrier.contains(&neighbor) {
+                    continue;
+                }
+                let Some(&cell) = map.get(neighbor) else { continue };
+                if cell == CellState::Obstacle {
+                    continue;
+                }
+                if visited.get(neighbor) == Some(&false) {
+                    visited.set(neighbor, true);
+                    queue.push_back(neighbor);
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Connected-component ("continent") labeling: every non-obstacle cell is
+    /// assigned an integer region id via a single row-major sweep -- each
+    /// unvisited `Empty`/`Unexplored` cell found starts a BFS that floods and
+    /// stamps its whole 4-connected component with a fresh label before the
+    /// sweep moves on. `Obstacle` cells get the `u32::MAX` sentinel. Two cells
+    /// are mutually reachable through known-passable ground iff they share a
+    /// label, so `RobotNode::path_exists` can answer that in O(1) after this
+    /// one O(width * height) pass instead of running a full search per query.
+    pub fn label_regions(map: &GridMap) -> Vec<u32> {
+        const UNLABELED: u32 = u32::MAX;
+
+        let mut labels = vec![UNLABELED; map.cells.len()];
+        let mut next_label: u32 = 0;
+
+        for start_idx in 0..map.cells.len() {
+            if map.cells[start_idx] == CellState::Obstacle || labels[start_idx] != UNLABELED {
+                continue;
+            }
+
+            let start = Point { x: (start_idx % map.width) as i32, y: (start_idx / map.width) as i32 };
+            labels[start_idx] = next_label;
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+
+            while let Some(pos) = queue.pop_front() {
+                for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                    let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                    let Some(neighbor_idx) = map.coord_to_index(neighbor) else { continue };
+                    if map.cells[neighbor_idx] == CellState::Obstacle || labels[neighbor_idx] != UNLABELED {
+                        continue;
+                    }
+                    labels[neighbor_idx] = next_label;
+                    queue.push_back(neighbor);
+                }
+            }
+
+            next_label += 1;
+        }
+
+        labels
     }
 
     /// Finds all frontier cells (EMPTY cells adjacent to UNEXPLORED cells).
+    ///
+    /// Implements Yamauchi-style frontier detection: a cell is a frontier if it is
+    /// itself `Empty` (reachable) and 8-adjacent to at least one `Unexplored` cell.
     pub fn find_frontier_cells(map: &GridMap) -> Vec<Point> {
-        // TODO: Implement
-        vec![]
+        map.iter_coords()
+            .filter(|&(_, &cell)| cell == CellState::Empty)
+            .filter(|&(pos, _)| {
+                EIGHT_NEIGHBORS.iter().any(|&(dx, dy)| {
+                    let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                    map.get(neighbor) == Some(&CellState::Unexplored)
+                })
+            })
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// Groups adjacent frontier cells into connected clusters via breadth-first search,
+    /// computing each cluster's centroid and cell count. Clusters smaller than
+    /// `MIN_FRONTIER_CLUSTER_SIZE` are discarded as sensor noise.
+    pub fn cluster_frontiers(map: &GridMap) -> Vec<Frontier> {
+        let frontier_cells = find_frontier_cells(map);
+        let frontier_set: HashSet<Point> = frontier_cells.iter().copied().collect();
+        let mut visited: HashSet<Point> = HashSet::new();
+        let mut clusters = Vec::new();
+
+        for &start in &frontier_cells {
+            if visited.contains(&start) {
+                continue;
+            }
+            let mut cells = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            visited.insert(start);
+
+            while let Some(pos) = queue.pop_front() {
+                cells.push(pos);
+                for &(dx, dy) in EIGHT_NEIGHBORS.iter() {
+                    let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                    if frontier_set.contains(&neighbor) && !visited.contains(&neighbor) {
+                        visited.insert(neighbor);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            if cells.len() >= MIN_FRONTIER_CLUSTER_SIZE {
+                let centroid = centroid_of(&cells);
+                let size = cells.len();
+                clusters.push(Frontier { centroid, cells, size });
+            }
+        }
+
+        clusters
+    }
+
+    /// Greedily assigns each robot (by current position) the nearest unclaimed
+    /// frontier cluster centroid, so that two robots don't converge on the same
+    /// frontier. Returns one assignment slot per robot, in the same order as
+    /// `robots`; a robot is left unassigned (`None`) once all clusters are claimed.
+    pub fn assign_frontiers(robots: &[Point], frontiers: &[Frontier]) -> Vec<Option<Point>> {
+        let mut claimed: HashSet<usize> = HashSet::new();
+        let mut assignments = vec![None; robots.len()];
+
+        loop {
+            // Resolve the globally closest (robot, frontier) pair first, so the robot
+            // nearest to a cluster claims it before farther-away robots are considered.
+            let mut best: Option<(usize, usize, i32)> = None;
+            for (ri, &pos) in robots.iter().enumerate() {
+                if assignments[ri].is_some() {
+                    continue;
+                }
+                for (fi, frontier) in frontiers.iter().enumerate() {
+                    if claimed.contains(&fi) {
+                        continue;
+                    }
+                    let cost = path_cost(pos, frontier.centroid);
+                    if best.map_or(true, |(_, _, best_cost)| cost < best_cost) {
+                        best = Some((ri, fi, cost));
+                    }
+                }
+            }
+
+            match best {
+                Some((ri, fi, _)) => {
+                    assignments[ri] = Some(frontiers[fi].centroid);
+                    claimed.insert(fi);
+                }
+                None => break,
+            }
+        }
+
+        assignments
+    }
+
+    /// Approximate path cost between two points. Until a planned route is available
+    /// this falls back to Manhattan distance, which is an admissible estimate on a
+    /// 4-connected grid.
+    fn path_cost(a: Point, b: Point) -> i32 {
+        (a.x - b.x).abs() + (a.y - b.y).abs()
+    }
+
+    /// Computes the centroid (average position, rounded toward zero) of a set of points.
+    fn centroid_of(cells: &[Point]) -> Point {
+        let sum_x: i32 = cells.iter().map(|p| p.x).sum();
+        let sum_y: i32 = cells.iter().map(|p| p.y).sum();
+        let len = cells.len() as i32;
+        Point { x: sum_x / len, y: sum_y / len }
+    }
+
+    /// Returns true if `pos` lies on the outer edge of the map.
+    fn is_on_map_edge(map: &GridMap, pos: Point) -> bool {
+        pos.x == 0 || pos.x == map.width as i32 - 1 || pos.y == 0 || pos.y == map.height as i32 - 1
+    }
+
+    /// True if `pos` is a portal seam cell -- the map edge there isn't actually
+    /// a wall, it continues into another stitched-together region. Loops that
+    /// only touch the map's outer edge through a seam like this aren't really
+    /// touching the world boundary, so they shouldn't be classified as exterior
+    /// on that basis alone.
+    fn is_portal_boundary_cell(map: &GridMap, pos: Point) -> bool {
+        match &map.topology {
+            Topology::Portals(portals) => portals.iter().any(|portal| portal.boundary_cell == pos),
+            Topology::Bounded | Topology::Toroidal => false,
+        }
+    }
+
+    /// Applies the configured `BoundaryCondition` to a planned step, invoked
+    /// whenever `attempted` would leave `[0,width) x [0,height)`. Returns the
+    /// resolved position, the (possibly updated) orientation, and a
+    /// `BoundaryHitEvent` if the step actually crossed the edge so the caller can
+    /// observe/log the interaction.
+    pub fn apply_boundary_condition(
+        current: Point,
+        attempted: Point,
+        orientation_rad: f64,
+        map: &GridMap,
+        condition: BoundaryCondition,
+    ) -> (Point, f64, Option<BoundaryHitEvent>) {
+        if map.bounds().contains(attempted) {
+            return (attempted, orientation_rad, None);
+        }
+
+        let event = Some(BoundaryHitEvent { attempted, condition });
+
+        match condition {
+            BoundaryCondition::Absorb => (current, orientation_rad, event),
+            BoundaryCondition::Reflect => {
+                let reflected_orientation = (orientation_rad + PI).rem_euclid(2.0 * PI);
+                (current, reflected_orientation, event)
+            }
+            BoundaryCondition::Wrap => {
+                let width = map.width as i32;
+                let height = map.height as i32;
+                let wrapped = Point {
+                    x: attempted.x.rem_euclid(width),
+                    y: attempted.y.rem_euclid(height),
+                };
+                (wrapped, orientation_rad, event)
+            }
+        }
+    }
+
+    /// Labels connected components of `target` (4-connectivity BFS) and flips any
+    /// component smaller than `min_area` to the dominant surrounding state. Removes
+    /// spurious single-cell artifacts (e.g. lone `Obstacle` specks, tiny `Unexplored`
+    /// pockets) that merged maps from multiple robots tend to accumulate and that
+    /// would otherwise confuse `is_boundary_closed_loop` and frontier detection.
+    /// Returns the number of regions removed.
+    pub fn remove_small_regions(map: &mut GridMap, min_area: usize, target: CellState) -> usize {
+        let mut visited = vec![false; map.width * map.height];
+        let mut removed = 0;
+
+        let candidates: Vec<Point> = map.iter_coords()
+            .filter(|&(_, &cell)| cell == target)
+            .map(|(pos, _)| pos)
+            .collect();
+
+        for start in candidates {
+            let start_idx = map.coord_to_index(start).unwrap();
+            if visited[start_idx] {
+                continue;
+            }
+
+            let mut region = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            visited[start_idx] = true;
+
+            while let Some(pos) = queue.pop_front() {
+                region.push(pos);
+                for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                    let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                    if map.get(neighbor) != Some(&target) {
+                        continue;
+                    }
+                    let idx = map.coord_to_index(neighbor).unwrap();
+                    if !visited[idx] {
+                        visited[idx] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+
+            if region.len() < min_area {
+                let replacement = dominant_surrounding_state(map, &region);
+                for &pos in &region {
+                    map.set(pos, replacement);
+                }
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Determines the most common `CellState` bordering a region, to replace it
+    /// with when the region is too small to be a real feature.
+    fn dominant_surrounding_state(map: &GridMap, region: &[Point]) -> CellState {
+        let region_set: HashSet<Point> = region.iter().copied().collect();
+        let mut empty_count = 0usize;
+        let mut obstacle_count = 0usize;
+        let mut unexplored_count = 0usize;
+
+        for &pos in region {
+            for &(dx, dy) in FOUR_NEIGHBORS.iter() {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if region_set.contains(&neighbor) {
+                    continue;
+                }
+                match map.get(neighbor) {
+                    Some(CellState::Empty) | Some(CellState::Goal) => empty_count += 1,
+                    Some(CellState::Obstacle) => obstacle_count += 1,
+                    Some(CellState::Unexplored) => unexplored_count += 1,
+                    None => {}
+                }
+            }
+        }
+
+        // Default to Empty when neither Obstacle nor Unexplored strictly outnumbers
+        // both other counts (including any tie) -- the common case is a
+        // lone Obstacle speck or a tiny Unexplored pocket inside open space.
+        if obstacle_count > empty_count && obstacle_count > unexplored_count {
+            CellState::Obstacle
+        } else if unexplored_count > empty_count && unexplored_count > obstacle_count {
+            CellState::Unexplored
+        } else {
+            CellState::Empty
+        }
     }
-} 
\ No newline at end of file
+}
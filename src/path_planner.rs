@@ -1,12 +1,303 @@
 use crate::types::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 
 /// Path planning utilities.
 pub mod path_planner {
     use super::*;
+    use crate::constants::{EIGHT_NEIGHBORS, CLEARANCE_WEIGHT};
+
+    /// Four-connected movement offsets used by the planner.
+    const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+    /// Controls whether `Unexplored` cells may be planned through.
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub enum UnexploredPolicy {
+        /// Treat Unexplored cells as blocked (only known-Empty cells are traversable).
+        Blocked,
+        /// Treat Unexplored cells as traversable, optimistic about what lies beyond
+        /// the known map -- useful for planning toward a frontier goal.
+        Traversable,
+    }
+
+    /// A* open-set entry, ordered by ascending `f = g + h` cost (min-heap: `Ord` is reversed so `BinaryHeap` pops the lowest `f` first).
+    /// `f_cost` is `f64` (rather than `i32`) so the clearance penalty in
+    /// `step_cost` can contribute a fractional cost; `total_cmp` gives a
+    /// total order without the NaN pitfalls of `partial_cmp`.
+    #[derive(Copy, Clone, PartialEq)]
+    struct OpenEntry {
+        f_cost: f64,
+        pos: Point,
+    }
+
+    impl Eq for OpenEntry {}
+
+    impl Ord for OpenEntry {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.f_cost.total_cmp(&self.f_cost)
+        }
+    }
+
+    impl PartialOrd for OpenEntry {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    /// Implements A* search algorithm to find a path from start to goal.
+    /// Obstacle cells are always blocked; Unexplored cells are treated as blocked too,
+    /// so the planner only routes through ground the robot has actually seen.
+    /// Use `astar_with_policy` to plan optimistically through Unexplored space.
+    pub fn find_path(start: Point, goal: Point, map: &GridMap) -> Option<Vec<Point>> {
+        astar(map, start, goal)
+    }
+
+    /// Implements A* search from `start` to `goal`, treating `Unexplored`
+    /// cells as traversable -- optimistic about what lies beyond the known
+    /// map, so a robot can plan a route toward a frontier goal through ground
+    /// it hasn't sensed yet rather than refusing to route through it at all.
+    pub fn plan_path(start: Point, goal: Point, map: &GridMap) -> Option<Vec<Point>> {
+        astar_with_policy(map, start, goal, UnexploredPolicy::Traversable)
+    }
 
     /// Implements A* search algorithm to find a path from start to goal.
-    pub fn find_path(_start: Point, _goal: Point, _map: &GridMap) -> Option<Vec<Point>> {
-        // TODO: Implement
+    pub fn astar(map: &GridMap, start: Point, goal: Point) -> Option<Vec<Point>> {
+        astar_with_policy(map, start, goal, UnexploredPolicy::Blocked)
+    }
+
+    /// A* search with a configurable policy for Unexplored cells, 4-connected.
+    pub fn astar_with_policy(map: &GridMap, start: Point, goal: Point, unexplored_policy: UnexploredPolicy) -> Option<Vec<Point>> {
+        astar_with_policy_and_connectivity(map, start, goal, unexplored_policy, Connectivity::Four)
+    }
+
+    /// A* search with a configurable policy for Unexplored cells and movement
+    /// connectivity. Diagonal steps under `Connectivity::Eight` additionally
+    /// require both orthogonal cells between `pos` and the diagonal neighbor
+    /// to be passable, so a path can't clip through an obstacle corner.
+    pub fn astar_with_policy_and_connectivity(map: &GridMap, start: Point, goal: Point, unexplored_policy: UnexploredPolicy, connectivity: Connectivity) -> Option<Vec<Point>> {
+        if !is_passable(map, start, unexplored_policy) || !is_passable(map, goal, unexplored_policy) {
+            return None;
+        }
+        if start == goal {
+            return Some(vec![start]);
+        }
+
+        let clearance = map.distance_transform();
+
+        let mut open = BinaryHeap::new();
+        let mut g_cost: HashMap<Point, f64> = HashMap::new();
+        let mut came_from: HashMap<Point, Point> = HashMap::new();
+
+        g_cost.insert(start, 0.0);
+        open.push(OpenEntry { f_cost: heuristic(start, goal), pos: start });
+
+        while let Some(OpenEntry { pos, .. }) = open.pop() {
+            if pos == goal {
+                return Some(reconstruct_path(&came_from, pos));
+            }
+
+            let current_g = g_cost[&pos];
+            for neighbor in get_valid_movements_with_policy_and_connectivity(map, pos, unexplored_policy, connectivity) {
+                let tentative_g = current_g + step_cost(map, &clearance, neighbor);
+                if tentative_g < *g_cost.get(&neighbor).unwrap_or(&f64::INFINITY) {
+                    came_from.insert(neighbor, pos);
+                    g_cost.insert(neighbor, tentative_g);
+                    open.push(OpenEntry { f_cost: tentative_g + heuristic(neighbor, goal), pos: neighbor });
+                }
+            }
+        }
+
         None
     }
-} 
\ No newline at end of file
+
+    /// Cost of stepping into `pos`: a flat `1` plus a clearance penalty
+    /// `CLEARANCE_WEIGHT / (1 + clearance[pos])` that grows the closer `pos`
+    /// is to an obstacle or the map boundary, so the planner trades a little
+    /// extra length for more margin from walls. `CLEARANCE_WEIGHT == 0.0`
+    /// recovers plain unit-cost shortest-path search.
+    fn step_cost(map: &GridMap, clearance: &[u32], pos: Point) -> f64 {
+        let idx = map.coord_to_index(pos).unwrap();
+        1.0 + CLEARANCE_WEIGHT / (1.0 + clearance[idx] as f64)
+    }
+
+    /// Returns the passable 4-connected neighbors of `pos` (Unexplored cells blocked).
+    pub fn get_valid_movements(map: &GridMap, pos: Point) -> Vec<Point> {
+        get_valid_movements_with_policy(map, pos, UnexploredPolicy::Blocked)
+    }
+
+    /// Returns the passable 4-connected neighbors of `pos` under the given policy.
+    pub fn get_valid_movements_with_policy(map: &GridMap, pos: Point, unexplored_policy: UnexploredPolicy) -> Vec<Point> {
+        get_valid_movements_with_policy_and_connectivity(map, pos, unexplored_policy, Connectivity::Four)
+    }
+
+    /// Returns the passable neighbors of `pos` under the given policy and
+    /// connectivity. See `astar_with_policy_and_connectivity` for the
+    /// corner-clipping rule applied to diagonal neighbors.
+    pub fn get_valid_movements_with_policy_and_connectivity(map: &GridMap, pos: Point, unexplored_policy: UnexploredPolicy, connectivity: Connectivity) -> Vec<Point> {
+        let offsets: &[(i32, i32)] = match connectivity {
+            Connectivity::Four => &FOUR_NEIGHBORS,
+            Connectivity::Eight => &EIGHT_NEIGHBORS,
+        };
+
+        offsets.iter()
+            .filter(|&&(dx, dy)| {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                if !is_passable(map, neighbor, unexplored_policy) {
+                    return false;
+                }
+                if dx != 0 && dy != 0 {
+                    let orthogonal_x = Point { x: pos.x + dx, y: pos.y };
+                    let orthogonal_y = Point { x: pos.x, y: pos.y + dy };
+                    if !is_passable(map, orthogonal_x, unexplored_policy) || !is_passable(map, orthogonal_y, unexplored_policy) {
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(|&(dx, dy)| Point { x: pos.x + dx, y: pos.y + dy })
+            .collect()
+    }
+
+    /// Manhattan distance heuristic, admissible on a 4-connected grid: every
+    /// step costs at least `1` even after the clearance penalty is added, so
+    /// this never overestimates the true remaining cost.
+    fn heuristic(a: Point, b: Point) -> f64 {
+        ((a.x - b.x).abs() + (a.y - b.y).abs()) as f64
+    }
+
+    fn is_passable(map: &GridMap, pos: Point, unexplored_policy: UnexploredPolicy) -> bool {
+        if pos.x < 0 || pos.y < 0 || pos.x >= map.width as i32 || pos.y >= map.height as i32 {
+            return false;
+        }
+        let idx = (pos.y as usize) * map.width + (pos.x as usize);
+        match map.cells[idx] {
+            CellState::Obstacle => false,
+            CellState::Empty | CellState::Goal => true,
+            CellState::Unexplored => unexplored_policy == UnexploredPolicy::Traversable,
+        }
+    }
+
+    /// Walks the came-from map backward from `goal` to `start` and reverses it into
+    /// a start-to-goal path.
+    fn reconstruct_path(came_from: &HashMap<Point, Point>, goal: Point) -> Vec<Point> {
+        let mut path = vec![goal];
+        let mut current = goal;
+        while let Some(&prev) = came_from.get(&current) {
+            path.push(prev);
+            current = prev;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Number of relaxation passes `smooth` applies after greedy shortcutting.
+    const SMOOTHING_ITERATIONS: usize = 3;
+
+    /// Collapses a jagged cell-by-cell `path` (as produced by wall following,
+    /// or stored in `BoundaryScoutState::path`) into a short waypoint list, so
+    /// a robot replaying an already-explored route doesn't have to step
+    /// through every traced cell. Two passes: greedy line-of-sight
+    /// shortcutting, then a few rounds of neighbor-average relaxation.
+    pub fn smooth(path: &[Point], map: &GridMap) -> Vec<Point> {
+        if path.is_empty() {
+            return Vec::new();
+        }
+
+        relax_waypoints(shortcut(path, map), map)
+    }
+
+    /// Starting from `path[0]`, repeatedly advances as far as possible through
+    /// `path` while a straight line back to the current anchor stays on
+    /// known-passable cells, emits the farthest visible point as the next
+    /// waypoint, then repeats from there -- the standard greedy string-pulling
+    /// shortcut for a grid path.
+    fn shortcut(path: &[Point], map: &GridMap) -> Vec<Point> {
+        let mut waypoints = vec![path[0]];
+        let mut anchor = 0;
+
+        while anchor < path.len() - 1 {
+            let mut farthest = anchor + 1;
+            for candidate in (anchor + 2)..path.len() {
+                if has_line_of_sight(map, path[anchor], path[candidate]) {
+                    farthest = candidate;
+                } else {
+                    break;
+                }
+            }
+            waypoints.push(path[farthest]);
+            anchor = farthest;
+        }
+
+        waypoints
+    }
+
+    /// Discrete analogue of trajectory-optimization smoothing: each interior
+    /// waypoint is pulled toward the midpoint of its neighbors, but the move
+    /// is clamped back to the original point whenever it would lose
+    /// line-of-sight to either neighbor, so relaxation can round a jagged
+    /// staircase into a diagonal without cutting through an obstacle.
+    fn relax_waypoints(waypoints: Vec<Point>, map: &GridMap) -> Vec<Point> {
+        if waypoints.len() < 3 {
+            return waypoints;
+        }
+
+        let mut points = waypoints;
+        for _ in 0..SMOOTHING_ITERATIONS {
+            let mut next = points.clone();
+            for i in 1..points.len() - 1 {
+                let prev = points[i - 1];
+                let following = points[i + 1];
+                let midpoint = Point {
+                    x: (prev.x + following.x).div_euclid(2),
+                    y: (prev.y + following.y).div_euclid(2),
+                };
+
+                if has_line_of_sight(map, prev, midpoint) && has_line_of_sight(map, midpoint, following) {
+                    next[i] = midpoint;
+                }
+            }
+            points = next;
+        }
+
+        points
+    }
+
+    /// True iff every cell on the Bresenham line from `a` to `b` is a known,
+    /// in-bounds, non-obstacle cell -- `Unexplored` counts as blocked, since a
+    /// shortcut has no business cutting through ground the robot hasn't
+    /// actually seen.
+    fn has_line_of_sight(map: &GridMap, a: Point, b: Point) -> bool {
+        bresenham_cells(a, b).iter().all(|&cell| is_passable(map, cell, UnexploredPolicy::Blocked))
+    }
+
+    /// Every grid cell the Bresenham line from `a` to `b` passes through,
+    /// inclusive of both endpoints.
+    fn bresenham_cells(a: Point, b: Point) -> Vec<Point> {
+        let mut cells = Vec::new();
+
+        let dx = (b.x - a.x).abs();
+        let dy = -(b.y - a.y).abs();
+        let step_x = if a.x < b.x { 1 } else { -1 };
+        let step_y = if a.y < b.y { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (a.x, a.y);
+
+        loop {
+            cells.push(Point { x, y });
+            if x == b.x && y == b.y {
+                break;
+            }
+            let doubled_err = 2 * err;
+            if doubled_err >= dy {
+                err += dy;
+                x += step_x;
+            }
+            if doubled_err <= dx {
+                err += dx;
+                y += step_y;
+            }
+        }
+
+        cells
+    }
+}
@@ -0,0 +1,109 @@
+/// Goal/feedback/result control surface for `RobotNode`, modeled on the
+/// goal/feedback/result pattern from action-based robotics middleware, so a
+/// supervisor process can command and observe a robot's progress
+/// programmatically instead of scraping `println!` output.
+
+use crate::types::*;
+
+/// A goal a supervisor can hand to a robot, preempting its autonomous
+/// exploration state machine until the goal succeeds or is preempted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    /// Resume the built-in autonomous boundary-scouting/interior-sweep state
+    /// machine; this is the robot's default behavior with no goal active.
+    ExploreBoundary,
+    /// Navigate to a single target cell.
+    ReachCell(Point),
+    /// Explore every cell within `region` until none of them are `Unexplored`
+    /// in this robot's own map.
+    SweepRegion(Rect),
+}
+
+/// A snapshot of progress, available every tick while a goal is active.
+#[derive(Clone, Copy, Debug)]
+pub struct Feedback {
+    pub pose: Pose,
+    pub phase: RobotPhase,
+    pub steps_taken: u32,
+    pub fraction_explored: f64,
+}
+
+/// How an active goal ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GoalOutcome {
+    Succeeded,
+    Preempted,
+}
+
+/// The terminal report for a goal, available once it has ended.
+#[derive(Clone, Copy, Debug)]
+pub struct GoalResult {
+    pub outcome: GoalOutcome,
+    pub steps_taken: u32,
+}
+
+/// Tracks the goal currently accepted by a `RobotNode`, if any, and the
+/// preempt/result handshake around it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ActionServer {
+    goal: Option<Goal>,
+    preempt_requested: bool,
+    steps_taken: u32,
+    last_result: Option<GoalResult>,
+}
+
+impl ActionServer {
+    /// Accepts a new goal, discarding any previous one's unclaimed result.
+    pub fn accept_goal(&mut self, goal: Goal) {
+        self.goal = Some(goal);
+        self.preempt_requested = false;
+        self.steps_taken = 0;
+        self.last_result = None;
+    }
+
+    pub fn goal(&self) -> Option<Goal> {
+        self.goal
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.goal.is_some()
+    }
+
+    /// Requests that the active goal be cleanly aborted on the next tick.
+    /// A no-op if no goal is active.
+    pub fn preempt(&mut self) {
+        if self.goal.is_some() {
+            self.preempt_requested = true;
+        }
+    }
+
+    /// True once a preempt has been requested and not yet honored.
+    pub fn should_abort(&self) -> bool {
+        self.goal.is_some() && self.preempt_requested
+    }
+
+    pub fn steps_taken(&self) -> u32 {
+        self.steps_taken
+    }
+
+    /// Consumes and returns the most recently finished goal's result, if any
+    /// hasn't already been claimed.
+    pub fn take_result(&mut self) -> Option<GoalResult> {
+        self.last_result.take()
+    }
+
+    /// Records that one more tick was spent pursuing the active goal.
+    pub fn record_tick(&mut self) {
+        if self.goal.is_some() {
+            self.steps_taken += 1;
+        }
+    }
+
+    /// Ends the active goal with `outcome`, stashing its result for the
+    /// supervisor to collect via `take_result`.
+    pub fn finish(&mut self, outcome: GoalOutcome) {
+        self.last_result = Some(GoalResult { outcome, steps_taken: self.steps_taken });
+        self.goal = None;
+        self.preempt_requested = false;
+    }
+}
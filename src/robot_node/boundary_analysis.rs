@@ -2,6 +2,7 @@
 
 use crate::types::*;
 use crate::constants::*;
+use crate::map_manager::map_manager;
 
 /// Boundary analysis utilities
 pub struct BoundaryAnalyzer;
@@ -38,6 +39,73 @@ impl BoundaryAnalyzer {
         }
     }
 
+    /// Classifies a completed boundary trace using the discrete turning-number
+    /// theorem: for a simple closed wall-following loop, the signed sum of 90°
+    /// turns -- including the closing turn that realigns each tracer with its
+    /// own starting heading -- must have magnitude exactly
+    /// `EXPECTED_ROTATION_DIFFERENCE` (4 steps, i.e. 360°). `total_rotation_steps`
+    /// alone (as accumulated incrementally during scouting) omits that closing
+    /// turn, since it only counts turns actually taken between moves, so callers
+    /// pass it in separately as `closing_turn` (see `closing_turn_steps`).
+    ///
+    /// Generalizes `analyze_boundary_by_rotation` (which implicitly assumes the
+    /// closing turn is zero) to the case where the robots rendezvous before
+    /// either has turned back to face its starting heading.
+    pub fn analyze_completed_loop(
+        robot0_rotation: Option<i32>,
+        robot0_closing_turn: i32,
+        robot1_rotation: Option<i32>,
+        robot1_closing_turn: i32,
+    ) -> BoundaryAnalysisResult {
+        match (robot0_rotation, robot1_rotation) {
+            (Some(rot0), Some(rot1)) => {
+                let winding_number = (rot0 + robot0_closing_turn) - (rot1 + robot1_closing_turn);
+                if winding_number == -EXPECTED_ROTATION_DIFFERENCE {
+                    BoundaryAnalysisResult::ExteriorWall
+                } else if winding_number == EXPECTED_ROTATION_DIFFERENCE {
+                    BoundaryAnalysisResult::Island
+                } else {
+                    BoundaryAnalysisResult::Incomplete
+                }
+            }
+            _ => BoundaryAnalysisResult::Incomplete,
+        }
+    }
+
+    /// Classifies a single tracer's own fully-closed boundary loop (its path
+    /// returns to its start) from just its own accumulated turning number and
+    /// the hand rule it traced with -- no partner rotation data required. For
+    /// any simple closed curve the net turning has magnitude exactly
+    /// `EXPECTED_ROTATION_DIFFERENCE` quarter-turns; its sign, combined with
+    /// `tracing_direction`, says which side of the wall the tracer walked on.
+    /// A right-hand tracer walks the mirror image of the same loop a
+    /// left-hand tracer would, so its turning number carries the opposite
+    /// sign for the same classification -- normalize to the left-hand
+    /// tracer's convention (the one `analyze_boundary_by_rotation` uses for
+    /// `ROBOT_LEFT_HAND`) before comparing.
+    pub fn classify_single_tracer_loop(total_rotation_steps: i32, tracing_direction: i8) -> BoundaryAnalysisResult {
+        let normalized = if tracing_direction == LEFT_HAND_RULE {
+            total_rotation_steps
+        } else {
+            -total_rotation_steps
+        };
+
+        if normalized == EXPECTED_ROTATION_DIFFERENCE {
+            BoundaryAnalysisResult::Island
+        } else if normalized == -EXPECTED_ROTATION_DIFFERENCE {
+            BoundaryAnalysisResult::ExteriorWall
+        } else {
+            BoundaryAnalysisResult::Incomplete
+        }
+    }
+
+    /// The extra signed turn (in 90° steps) a tracer would need to realign its
+    /// `current_heading` with its own `starting_heading` -- the piece
+    /// `total_rotation_steps` leaves out when the trace hasn't literally closed.
+    pub fn closing_turn_steps(current_heading: Direction, starting_heading: Direction) -> i32 {
+        current_heading.rotation_steps_to(starting_heading)
+    }
+
     /// Analyze if a boundary path forms a closed loop
     pub fn is_boundary_closed_loop(path: &[Point]) -> bool {
         if path.len() < 3 {
@@ -48,29 +116,119 @@ impl BoundaryAnalyzer {
         path.first() == path.last()
     }
 
-    /// Determine if a closed boundary is an island (obstacle) rather than exterior wall
-    /// Returns true if it's an island, false if it's an exterior wall
-    pub fn is_island_not_exterior(path: &[Point], map_width: usize, map_height: usize) -> bool {
-        // If any point in the path touches the map boundaries, it's an exterior wall
-        for point in path {
-            if point.x == 0 || point.x == (map_width as i32 - 1) ||
-               point.y == 0 || point.y == (map_height as i32 - 1) {
-                return false; // Touches boundary -> exterior wall
-            }
-        }
-        
-        true // No boundary contact -> island/obstacle
+    /// Determine if a closed boundary is an island (obstacle) rather than exterior wall.
+    /// Returns true if it's an island, false if it's an exterior wall.
+    ///
+    /// Delegates to a flood-fill classification from the map's outer edge rather than
+    /// just checking whether the path touches the border, so concave obstacles and
+    /// partial loops are classified correctly.
+    pub fn is_island_not_exterior(path: &[Point], map: &GridMap) -> bool {
+        map_manager::is_loop_an_island(map, path)
     }
 
-    /// Legacy path-based analysis method (for backwards compatibility)
-    pub fn analyze_boundary_by_path(path: &[Point], map_width: usize, map_height: usize) -> BoundaryAnalysisResult {
-        // For iterative boundary scouting, check if any point touches map boundaries
-        if Self::is_island_not_exterior(path, map_width, map_height) {
-            // No boundary contact in the path -> likely an island
+    /// Path-based analysis method, used as a fallback when rotation data is missing.
+    pub fn analyze_boundary_by_path(path: &[Point], map: &GridMap) -> BoundaryAnalysisResult {
+        if Self::is_island_not_exterior(path, map) {
             BoundaryAnalysisResult::Island
         } else {
-            // Path touches boundary -> likely exterior wall
             BoundaryAnalysisResult::ExteriorWall
         }
     }
+
+    /// The signed turning number (in 90° steps) of a recorded path, computed
+    /// purely from the sequence of positions rather than from incrementally
+    /// tracked heading changes -- `classify_single_tracer_loop`'s theorem
+    /// applied to a `path: &[Point]` gathered however the caller likes,
+    /// instead of requiring a live `BoundaryScoutState` to have tallied it
+    /// move-by-move. Includes the closing turn back to the first heading, so
+    /// a genuinely closed loop's result is directly comparable against
+    /// `EXPECTED_ROTATION_DIFFERENCE` with no separate `closing_turn_steps`
+    /// call needed. Returns `None` for a path too short to have a heading.
+    ///
+    /// Headings are measured in degrees rather than via `Direction` (which
+    /// only recognizes the four cardinal unit vectors), so a path containing
+    /// `Connectivity::Eight` diagonal steps still turns out a correct
+    /// winding number instead of silently degrading to `None`/`Incomplete`.
+    /// For a genuinely closed simple loop the signed turn total is always an
+    /// exact multiple of 360 regardless of whether the individual steps were
+    /// 45 or 90 degrees apart, so dividing by 90 at the end still lands on
+    /// the same integer quarter-turn count `EXPECTED_ROTATION_DIFFERENCE` uses.
+    fn turning_number(path: &[Point]) -> Option<i32> {
+        let headings: Vec<i32> = path.windows(2)
+            .map(|pair| Self::step_angle_deg(pair[1].x - pair[0].x, pair[1].y - pair[0].y))
+            .collect::<Option<_>>()?;
+
+        if headings.len() < 2 {
+            return None;
+        }
+
+        let mut total: i32 = headings.windows(2)
+            .map(|pair| Self::turn_deg(pair[0], pair[1]))
+            .sum();
+        total += Self::turn_deg(*headings.last().unwrap(), *headings.first().unwrap());
+        Some(total / 90)
+    }
+
+    /// The clockwise angle, in whole degrees, of a unit (or diagonal-unit)
+    /// step vector -- East = 0, South = 90, West = 180, North = 270, with the
+    /// four diagonals at the intervening multiples of 45 -- or `None` for the
+    /// zero vector. Generalizes `Direction::from_vector` (cardinal-only) to
+    /// the 8-connected steps `Connectivity::Eight` movement can produce.
+    fn step_angle_deg(dx: i32, dy: i32) -> Option<i32> {
+        if dx == 0 && dy == 0 {
+            return None;
+        }
+        let angle = (dy as f64).atan2(dx as f64).to_degrees().round() as i32;
+        Some(angle.rem_euclid(360))
+    }
+
+    /// The signed turn, in degrees within `(-180, 180]`, from heading `from`
+    /// to heading `to` (both as returned by `step_angle_deg`), taking the
+    /// shorter way around.
+    fn turn_deg(from: i32, to: i32) -> i32 {
+        let diff = (to - from).rem_euclid(360);
+        if diff > 180 { diff - 360 } else { diff }
+    }
+
+    /// Classifies one closed sub-loop of a traced path via the winding-number
+    /// theorem, additionally reporting how many cells it encloses (via
+    /// `map_manager::count_enclosed_cells`) so a tiny pillar island can be
+    /// told apart from a large interior courtyard -- `classify_single_tracer_loop`
+    /// plus area, for callers that already have a recorded path rather than a
+    /// live rotation tally.
+    pub fn classify_loop_winding(path: &[Point], map: &GridMap, tracing_direction: i8) -> LoopWinding {
+        let result = match Self::turning_number(path) {
+            Some(steps) => Self::classify_single_tracer_loop(steps, tracing_direction),
+            None => BoundaryAnalysisResult::Incomplete,
+        };
+
+        let enclosed_cells = if result == BoundaryAnalysisResult::Island {
+            map_manager::count_enclosed_cells(map, path)
+        } else {
+            0
+        };
+
+        LoopWinding { result, enclosed_cells }
+    }
+
+    /// Splits a traced path into its closed sub-loops -- each place the
+    /// tracer's position repeats an earlier one marks a loop closing -- and
+    /// classifies every one independently, so a single scouting mission that
+    /// circles more than one obstacle before rendezvousing reports every
+    /// island it found rather than only the loop formed by the path's first
+    /// and last point.
+    pub fn classify_sub_loops(path: &[Point], map: &GridMap, tracing_direction: i8) -> Vec<LoopWinding> {
+        let mut loops = Vec::new();
+        let mut leg_start = 0;
+
+        for end in (leg_start + 1)..path.len() {
+            if let Some(offset) = path[leg_start..end].iter().position(|&p| p == path[end]) {
+                let loop_start = leg_start + offset;
+                loops.push(Self::classify_loop_winding(&path[loop_start..=end], map, tracing_direction));
+                leg_start = end;
+            }
+        }
+
+        loops
+    }
 }
\ No newline at end of file
@@ -4,11 +4,23 @@ pub mod phase_trait;
 pub mod wall_following;
 pub mod boundary_analysis;
 pub mod phases;
+pub mod sensing;
+pub mod action_server;
+pub mod narration;
+pub mod transition;
 
 use crate::types::*;
 use crate::constants::*;
+use crate::map_manager::map_manager;
+use crate::path_planner::path_planner::{self, UnexploredPolicy};
 use phase_trait::*;
 use phases::*;
+use action_server::{ActionServer, Goal, Feedback, GoalOutcome, GoalResult};
+use narration::{Bearing, NarrationDetail};
+
+/// Regions of a merged-in `CellState` smaller than this are denoising artifacts,
+/// not real features, and get flipped to the dominant surrounding state.
+const MIN_REGION_AREA: usize = 2;
 
 /// Main robot logic node, encapsulating state and behavior.
 #[derive(Debug, Clone)]
@@ -21,6 +33,17 @@ pub struct RobotNode {
     island_escape: IslandEscapePhase,
     interior_sweep: InteriorSweepPhase,
     central_scan: CentralScanPhase,
+    frontier_exploration: FrontierExplorationPhase,
+    /// Goal/feedback/result control surface letting a supervisor override the
+    /// autonomous phase machine below. See `action_server`.
+    action_server: ActionServer,
+    /// Cached `map_manager::label_regions` of `state.map`, used by
+    /// `path_exists`. Recomputed lazily -- only when `region_labels_dirty`
+    /// is set -- rather than relabeling the whole map on every query.
+    region_labels: Vec<u32>,
+    /// Set by `update_local_map`/`merge_map` whenever `state.map` changes;
+    /// cleared the next time `path_exists` recomputes `region_labels`.
+    region_labels_dirty: bool,
 }
 
 impl RobotNode {
@@ -34,33 +57,173 @@ impl RobotNode {
             island_escape: IslandEscapePhase,
             interior_sweep: InteriorSweepPhase,
             central_scan: CentralScanPhase,
+            frontier_exploration: FrontierExplorationPhase,
+            action_server: ActionServer::default(),
+            region_labels: Vec::new(),
+            region_labels_dirty: true,
+        }
+    }
+
+    /// Returns true iff `a` and `b` are mutually reachable through this
+    /// robot's currently known map (`Empty`/`Unexplored` cells, 4-connected)
+    /// -- i.e. share a `map_manager::label_regions` id. Lets callers (e.g.
+    /// deciding whether to commit to `IslandEscape` or a rendezvous) check
+    /// whether a target is even in the same reachable region before
+    /// spending ticks trying to reach it.
+    pub fn path_exists(&mut self, a: Point, b: Point) -> bool {
+        if self.region_labels_dirty {
+            self.region_labels = map_manager::label_regions(&self.state.map);
+            self.region_labels_dirty = false;
+        }
+
+        let (Some(a_idx), Some(b_idx)) = (self.state.map.coord_to_index(a), self.state.map.coord_to_index(b)) else {
+            return false;
+        };
+
+        self.region_labels[a_idx] != u32::MAX && self.region_labels[a_idx] == self.region_labels[b_idx]
+    }
+
+    /// Accepts a goal from a supervisor, preempting autonomous exploration
+    /// until it succeeds or is itself preempted.
+    pub fn accept_goal(&mut self, goal: Goal) {
+        self.action_server.accept_goal(goal);
+    }
+
+    /// True while a supervisor-issued goal is in progress.
+    pub fn is_active(&self) -> bool {
+        self.action_server.is_active()
+    }
+
+    /// Requests that the active goal be cleanly aborted on the next `tick`.
+    pub fn preempt(&mut self) {
+        self.action_server.preempt();
+    }
+
+    /// Consumes the most recently finished goal's result, if the supervisor
+    /// hasn't already collected it.
+    pub fn take_result(&mut self) -> Option<GoalResult> {
+        self.action_server.take_result()
+    }
+
+    /// A snapshot of this robot's current progress, readable at any time
+    /// (not just right after `tick`).
+    pub fn feedback(&self) -> Feedback {
+        let explored = self.state.map.cells.iter().filter(|&&c| c != CellState::Unexplored).count();
+        Feedback {
+            pose: self.state.pose,
+            phase: self.state.phase,
+            steps_taken: self.action_server.steps_taken(),
+            fraction_explored: explored as f64 / self.state.map.cells.len() as f64,
         }
     }
 
-    /// The main decision-making loop, called on each simulation tick.
-    /// Only performs movement; sensing and communication are handled externally.
-    pub fn tick(&mut self, all_robots: &[RobotNode], global_map: &GridMap) {
+    /// Narrates what this robot currently perceives from its own point of
+    /// view -- e.g. `"wall ahead"`, `"opening to my left"`, `"partner
+    /// behind-right"` -- instead of raw map coordinates, for logs or any
+    /// future UI. Only reports neighbors this robot has actually sensed
+    /// (`Unexplored` cells are silently skipped) and the partner's bearing,
+    /// if the partner is known to `all_robots`.
+    pub fn describe_surroundings(&self, all_robots: &[RobotNode], detail: NarrationDetail) -> Vec<String> {
+        let heading = Direction::from_rad(self.state.pose.orientation_rad);
+        let position = self.state.pose.position;
+        let mut lines = Vec::new();
+
+        for dir in [Direction::North, Direction::East, Direction::South, Direction::West] {
+            let (dx, dy) = dir.to_vector();
+            let neighbor = Point { x: position.x + dx, y: position.y + dy };
+            let cell = match self.state.map.get(neighbor) {
+                Some(&cell) => cell,
+                None => continue,
+            };
+            let bearing = Bearing::classify(narration::signed_angle_to(heading, Point { x: dx, y: dy }), detail);
+            match cell {
+                CellState::Obstacle => lines.push(format!("wall {}", bearing.phrase())),
+                CellState::Empty => lines.push(format!("opening {}", bearing.phrase())),
+                CellState::Goal => lines.push(format!("goal {}", bearing.phrase())),
+                CellState::Unexplored => {}
+            }
+        }
+
+        if let Some(partner) = all_robots.iter().find(|r| r.state.id == self.state.partner_id) {
+            let offset = Point {
+                x: partner.state.pose.position.x - position.x,
+                y: partner.state.pose.position.y - position.y,
+            };
+            let bearing = Bearing::classify(narration::signed_angle_to(heading, offset), detail);
+            lines.push(format!("partner {}", bearing.phrase()));
+        }
+
+        lines
+    }
+
+    /// The main decision-making loop, called on each simulation tick. Only
+    /// performs movement; sensing and communication are handled externally.
+    /// Returns `true` whenever the robot's phase is `Idle` after this tick
+    /// (i.e. it has nothing left to do), whether that's from the autonomous
+    /// machine finishing or an aborted goal being preempted; a goal that
+    /// succeeds leaves `state.phase` as it was.
+    ///
+    /// Deposits `deposit_amount` of stigmergic trail onto `pheromone` (a
+    /// `global_map.width`-wide grid parallel to `global_map.cells`) at this
+    /// robot's current position before acting, so phases reading
+    /// `PhaseContext::pheromone` this tick already see this robot's own
+    /// presence, not just the partner's. Decaying the layer is the caller's
+    /// job (e.g. `SimulationManager::tick`), once per simulation tick rather
+    /// than once per robot.
+    pub fn tick(&mut self, all_robots: &[RobotNode], global_map: &GridMap, pheromone: &mut [f32], deposit_amount: f32) -> bool {
+        self.deposit_pheromone(global_map, pheromone, deposit_amount);
+
+        if self.action_server.should_abort() {
+            self.abort_active_goal();
+            return self.state.phase == RobotPhase::Idle;
+        }
+
+        self.action_server.record_tick();
+
+        match self.action_server.goal() {
+            Some(Goal::ReachCell(target)) => self.tick_reach_cell(target),
+            Some(Goal::SweepRegion(region)) => self.tick_sweep_region(region),
+            Some(Goal::ExploreBoundary) | None => self.tick_autonomous(all_robots, global_map, pheromone),
+        }
+
+        self.state.phase == RobotPhase::Idle
+    }
+
+    /// Adds `amount` to `pheromone`'s entry for this robot's current
+    /// position. Out-of-bounds positions (shouldn't happen, but cheaper to
+    /// guard than to assume) are silently skipped.
+    fn deposit_pheromone(&self, global_map: &GridMap, pheromone: &mut [f32], amount: f32) {
+        if let Some(idx) = global_map.coord_to_index(self.state.pose.position) {
+            if let Some(cell) = pheromone.get_mut(idx) {
+                *cell += amount;
+            }
+        }
+    }
+
+    /// Runs the built-in autonomous phase state machine for one tick.
+    fn tick_autonomous(&mut self, all_robots: &[RobotNode], global_map: &GridMap, pheromone: &[f32]) {
+        self.state.tick_count += 1;
+
         let context = PhaseContext {
             all_robots,
             global_map,
+            pheromone,
         };
 
-        let transition = match self.state.phase {
+        let from_phase = self.state.phase;
+        let transition = match from_phase {
             RobotPhase::InitialWallFind => self.wall_find.execute(&mut self.state, &context),
             RobotPhase::BoundaryScouting => self.boundary_scouting.execute(&mut self.state, &context),
             RobotPhase::BoundaryAnalysis => self.boundary_analysis.execute(&mut self.state, &context),
             RobotPhase::IslandEscape => self.island_escape.execute(&mut self.state, &context),
             RobotPhase::InteriorSweep => self.interior_sweep.execute(&mut self.state, &context),
             RobotPhase::CentralScan => self.central_scan.execute(&mut self.state, &context),
+            RobotPhase::FrontierExploration => self.frontier_exploration.execute(&mut self.state, &context),
             _ => PhaseTransition::Continue,
         };
 
-        // Handle phase transitions
         match transition {
-            PhaseTransition::Transition(new_phase) => {
-                println!("Robot {} transitioning from {:?} to {:?}", self.state.id, self.state.phase, new_phase);
-                self.state.phase = new_phase;
-            },
+            PhaseTransition::Transition(to_phase) => self.apply_transition(from_phase, to_phase),
             PhaseTransition::Complete => {
                 println!("Robot {} completed all phases", self.state.id);
             },
@@ -70,27 +233,121 @@ impl RobotNode {
         }
     }
 
-    /// Update the robot's local map with its current cell and four neighbors from the global map.
+    /// Validates `from -> to` against `transition::TRANSITIONS` before
+    /// committing it. A declared transition runs `on_exit` on the outgoing
+    /// phase, assigns `state.phase`, runs `on_enter` on the incoming one, then
+    /// records `(tick_count, Transition)` on `state.phase_history` (capped at
+    /// `PHASE_HISTORY_LIMIT`). An undeclared jump
+    /// is rejected and logged instead of silently changing `state.phase`.
+    fn apply_transition(&mut self, from: RobotPhase, to: RobotPhase) {
+        let Some(transition) = transition::validate(from, to) else {
+            println!("Robot {} rejected illegal phase transition {:?} -> {:?}", self.state.id, from, to);
+            return;
+        };
+
+        println!("Robot {} transitioning from {:?} to {:?}: {}", self.state.id, from, to, transition.label);
+
+        self.call_on_exit(from);
+        self.state.phase = to;
+        self.call_on_enter(to);
+
+        self.state.phase_history.push((self.state.tick_count, transition));
+        if self.state.phase_history.len() > PHASE_HISTORY_LIMIT {
+            self.state.phase_history.remove(0);
+        }
+    }
+
+    /// Dispatches `RobotPhaseBehavior::on_enter` to whichever phase
+    /// implementation owns `phase`. `Idle` has no behavior struct, so it's a
+    /// no-op.
+    fn call_on_enter(&mut self, phase: RobotPhase) {
+        match phase {
+            RobotPhase::InitialWallFind => self.wall_find.on_enter(&mut self.state),
+            RobotPhase::BoundaryScouting => self.boundary_scouting.on_enter(&mut self.state),
+            RobotPhase::BoundaryAnalysis => self.boundary_analysis.on_enter(&mut self.state),
+            RobotPhase::IslandEscape => self.island_escape.on_enter(&mut self.state),
+            RobotPhase::InteriorSweep => self.interior_sweep.on_enter(&mut self.state),
+            RobotPhase::CentralScan => self.central_scan.on_enter(&mut self.state),
+            RobotPhase::FrontierExploration => self.frontier_exploration.on_enter(&mut self.state),
+            RobotPhase::Idle => {}
+        }
+    }
+
+    /// Dispatches `RobotPhaseBehavior::on_exit` to whichever phase
+    /// implementation owns `phase`. `Idle` has no behavior struct, so it's a
+    /// no-op.
+    fn call_on_exit(&mut self, phase: RobotPhase) {
+        match phase {
+            RobotPhase::InitialWallFind => self.wall_find.on_exit(&mut self.state),
+            RobotPhase::BoundaryScouting => self.boundary_scouting.on_exit(&mut self.state),
+            RobotPhase::BoundaryAnalysis => self.boundary_analysis.on_exit(&mut self.state),
+            RobotPhase::IslandEscape => self.island_escape.on_exit(&mut self.state),
+            RobotPhase::InteriorSweep => self.interior_sweep.on_exit(&mut self.state),
+            RobotPhase::CentralScan => self.central_scan.on_exit(&mut self.state),
+            RobotPhase::FrontierExploration => self.frontier_exploration.on_exit(&mut self.state),
+            RobotPhase::Idle => {}
+        }
+    }
+
+    /// Takes one A* step toward `target`, succeeding the active goal on arrival.
+    fn tick_reach_cell(&mut self, target: Point) {
+        if self.state.pose.position == target {
+            self.action_server.finish(GoalOutcome::Succeeded);
+            return;
+        }
+
+        let next = path_planner::astar_with_policy_and_connectivity(&self.state.map, self.state.pose.position, target, UnexploredPolicy::Traversable, self.state.connectivity)
+            .and_then(|path| path.get(1).copied());
+
+        if let Some(next_pos) = next {
+            self.state.pose.orientation_rad = wall_following::WallFollower::update_orientation_wrapped(self.state.pose.position, next_pos, &self.state.map);
+            self.state.pose.position = next_pos;
+        }
+    }
+
+    /// Steps toward the nearest (by Manhattan distance) still-`Unexplored`
+    /// cell within `region`, succeeding the active goal once the region is
+    /// fully explored.
+    fn tick_sweep_region(&mut self, region: Rect) {
+        let current = self.state.pose.position;
+        let target = self.state.map.iter_coords()
+            .filter(|&(p, &cell)| region.contains(p) && cell == CellState::Unexplored)
+            .map(|(p, _)| p)
+            .min_by_key(|p| (p.x - current.x).abs() + (p.y - current.y).abs());
+
+        match target {
+            Some(target) => self.tick_reach_cell(target),
+            None => self.action_server.finish(GoalOutcome::Succeeded),
+        }
+    }
+
+    /// Cleanly abandons whatever the robot was doing -- autonomous scouting
+    /// leg or goal-directed movement -- and parks it in `Idle`, reporting the
+    /// active goal (if any) as preempted.
+    fn abort_active_goal(&mut self) {
+        self.state.boundary_scout = None;
+        self.state.central_scan = None;
+        self.state.phase = RobotPhase::Idle;
+        self.action_server.finish(GoalOutcome::Preempted);
+    }
+
+    /// Update the robot's local map with everything visible from its current pose,
+    /// using recursive shadowcasting against the global map rather than granting
+    /// perfect knowledge of it. Also fires a longer-range forward ray fan so
+    /// boundary scouting and interior sweeping see obstacles coming from a
+    /// distance instead of only discovering them by bumping into them.
     pub fn update_local_map(&mut self, global_map: &GridMap) {
-        let width = self.state.map.width as i32;
-        let height = self.state.map.height as i32;
-        let pos = self.state.pose.position;
-        let mut to_update = vec![pos];
-        let dirs = [NORTH, SOUTH, EAST, WEST];
-        for (dx, dy) in &dirs {
-            let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
-            if neighbor.x >= 0 && neighbor.x < width && neighbor.y >= 0 && neighbor.y < height {
-                to_update.push(neighbor);
-            }
+        let visible = sensing::compute_visible_cells(&self.state.pose, global_map);
+        for (point, cell) in visible {
+            self.state.map.set(point, cell);
         }
 
-        for point in to_update {
-            let global_idx = (point.y as usize) * global_map.width + (point.x as usize);
-            let local_idx = (point.y as usize) * self.state.map.width + (point.x as usize);
-            if global_idx < global_map.cells.len() && local_idx < self.state.map.cells.len() {
-                self.state.map.cells[local_idx] = global_map.cells[global_idx];
-            }
+        let ahead = sensing::cast_ray_fan(&self.state.pose, global_map);
+        for (point, cell) in ahead {
+            self.state.map.set(point, cell);
         }
+
+        self.region_labels_dirty = true;
     }
 
     /// Returns true if two positions are within communication range
@@ -107,6 +364,7 @@ impl RobotNode {
                 let ch = match self.state.map.cells[idx] {
                     CellState::Obstacle => '#',
                     CellState::Empty => '.',
+                    CellState::Goal => 'O',
                     CellState::Unexplored => ' ',
                 };
                 if self.state.pose.position.x == x as i32 && self.state.pose.position.y == y as i32 {
@@ -119,12 +377,25 @@ impl RobotNode {
         }
     }
 
-    /// Merge another robot's map into this robot's map.
-    pub fn merge_map(&mut self, partner_map: &GridMap) {
+    /// Merge another robot's map into this robot's map, and record its pose
+    /// as `state.last_known_partner_pose` -- the caller only invokes this
+    /// when the two robots are in communication range, so this is also the
+    /// robot's only chance to learn where its partner currently is.
+    pub fn merge_map(&mut self, partner_map: &GridMap, partner_pose: Pose) {
+        self.state.last_known_partner_pose = Some(partner_pose);
+
         for (i, &cell) in partner_map.cells.iter().enumerate() {
             if cell != CellState::Unexplored {
                 self.state.map.cells[i] = cell;
             }
         }
+
+        // Denoise the merged map so a single mis-sensed cell doesn't masquerade as
+        // an obstacle speck or an unexplored pocket for downstream boundary/frontier
+        // analysis.
+        map_manager::remove_small_regions(&mut self.state.map, MIN_REGION_AREA, CellState::Obstacle);
+        map_manager::remove_small_regions(&mut self.state.map, MIN_REGION_AREA, CellState::Unexplored);
+
+        self.region_labels_dirty = true;
     }
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+/// Egocentric relative-bearing narration of a robot's sensed surroundings, so
+/// logs and any future UI can report what a robot perceives from its own
+/// frame of reference ("wall ahead", "opening to my left", "partner
+/// behind-right") instead of raw absolute grid coordinates.
+
+use crate::types::*;
+
+/// How finely bearings are bucketed. `FourBucket` only ever reports plain
+/// `Behind` for anything roughly opposite the heading; `EightBucket` further
+/// splits that range into `BehindLeft`/`BehindRight`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NarrationDetail {
+    FourBucket,
+    EightBucket,
+}
+
+/// A target's direction relative to the observer's own heading.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Bearing {
+    Ahead,
+    AheadLeft,
+    AheadRight,
+    Left,
+    Right,
+    BehindLeft,
+    BehindRight,
+    Behind,
+}
+
+impl Bearing {
+    /// Buckets the signed angle (degrees, positive = left of heading, in
+    /// `(-180, 180]`, see `signed_angle_to`) between a heading and a target
+    /// offset into a relative bearing.
+    pub fn classify(signed_angle_deg: f64, detail: NarrationDetail) -> Bearing {
+        let angle = signed_angle_deg.abs();
+        let left = signed_angle_deg > 0.0;
+
+        match detail {
+            NarrationDetail::FourBucket => {
+                if angle <= 15.0 {
+                    Bearing::Ahead
+                } else if angle <= 45.0 {
+                    if left { Bearing::AheadLeft } else { Bearing::AheadRight }
+                } else if angle <= 135.0 {
+                    if left { Bearing::Left } else { Bearing::Right }
+                } else {
+                    Bearing::Behind
+                }
+            }
+            NarrationDetail::EightBucket => {
+                if angle <= 22.5 {
+                    Bearing::Ahead
+                } else if angle <= 67.5 {
+                    if left { Bearing::AheadLeft } else { Bearing::AheadRight }
+                } else if angle <= 112.5 {
+                    if left { Bearing::Left } else { Bearing::Right }
+                } else if angle <= 157.5 {
+                    if left { Bearing::BehindLeft } else { Bearing::BehindRight }
+                } else {
+                    Bearing::Behind
+                }
+            }
+        }
+    }
+
+    /// A short compound label, e.g. `"ahead-left"` or `"behind"`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Bearing::Ahead => "ahead",
+            Bearing::AheadLeft => "ahead-left",
+            Bearing::AheadRight => "ahead-right",
+            Bearing::Left => "left",
+            Bearing::Right => "right",
+            Bearing::BehindLeft => "behind-left",
+            Bearing::BehindRight => "behind-right",
+            Bearing::Behind => "behind",
+        }
+    }
+
+    /// A natural-language phrase, e.g. `"to my left"` or `"ahead-left"`.
+    /// Plain left/right read more naturally this way; every other bearing is
+    /// already a sufficient compound word on its own.
+    pub fn phrase(self) -> String {
+        match self {
+            Bearing::Left => "to my left".to_string(),
+            Bearing::Right => "to my right".to_string(),
+            other => other.label().to_string(),
+        }
+    }
+}
+
+/// The signed angle (degrees, positive = left/counterclockwise of `heading`,
+/// in `(-180, 180]`) to the direction of `offset`. Mirrors the
+/// `cross_product > 0 => partner on the right` convention already used by
+/// `WallFollower::wall_follow_step_first_move`.
+pub fn signed_angle_to(heading: Direction, offset: Point) -> f64 {
+    if offset.x == 0 && offset.y == 0 {
+        return 0.0;
+    }
+    let (hx, hy) = heading.to_vector();
+    let dot = (hx * offset.x + hy * offset.y) as f64;
+    let cross = (hx * offset.y - hy * offset.x) as f64;
+    (-cross).atan2(dot).to_degrees()
+}
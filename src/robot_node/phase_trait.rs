@@ -1,11 +1,72 @@
 /// Trait-based phase system for robot behaviors
 
+use std::collections::{HashMap, HashSet};
+
 use crate::types::*;
+use crate::path_planner::path_planner;
 
 /// Context information passed to phase execution
 pub struct PhaseContext<'a> {
     pub all_robots: &'a [RobotNode],
     pub global_map: &'a GridMap,
+    /// Shared stigmergic trail layer, parallel to `global_map.cells`: higher
+    /// values mean more recently/heavily trodden ground. Read-only here --
+    /// depositing onto it happens once per robot in `RobotNode::tick`, and
+    /// decaying it happens once per simulation tick in the caller (e.g.
+    /// `SimulationManager::tick`) -- so phases can bias movement away from
+    /// it (see `WallFollower::wall_follow_step_with_repulsion`) without risking
+    /// a double deposit or decay.
+    pub pheromone: &'a [f32],
+}
+
+impl<'a> PhaseContext<'a> {
+    /// Auction-based frontier task allocation for any number of participating
+    /// robots: each robot in `participants` bids its own A* path cost (over
+    /// its own known map, via `path_planner::plan_path`) to every cluster's
+    /// centroid, and clusters are greedily awarded to the globally lowest
+    /// unassigned bid until every robot has a cluster or the clusters run
+    /// out. Generalizes the two-robot "nearest unclaimed centroid" approach
+    /// (`map_manager::assign_frontiers`, which estimates cost via Manhattan
+    /// distance) to N robots with a real routed cost.
+    pub fn auction_frontier_clusters(&self, participants: &[u8], frontiers: &[Frontier]) -> HashMap<u8, Point> {
+        let mut claimed: HashSet<usize> = HashSet::new();
+        let mut assignments: HashMap<u8, Point> = HashMap::new();
+
+        loop {
+            let mut best: Option<(u8, usize, i32)> = None;
+
+            for &id in participants {
+                if assignments.contains_key(&id) {
+                    continue;
+                }
+                let Some(robot) = self.all_robots.iter().find(|r| r.state.id == id) else { continue };
+
+                for (fi, cluster) in frontiers.iter().enumerate() {
+                    if claimed.contains(&fi) {
+                        continue;
+                    }
+                    let Some(bid) = path_planner::plan_path(robot.state.pose.position, cluster.centroid, &robot.state.map)
+                        .map(|path| path.len() as i32)
+                    else {
+                        continue;
+                    };
+                    if best.map_or(true, |(_, _, best_bid)| bid < best_bid) {
+                        best = Some((id, fi, bid));
+                    }
+                }
+            }
+
+            match best {
+                Some((id, fi, _)) => {
+                    assignments.insert(id, frontiers[fi].centroid);
+                    claimed.insert(fi);
+                }
+                None => break,
+            }
+        }
+
+        assignments
+    }
 }
 
 /// Result of phase execution
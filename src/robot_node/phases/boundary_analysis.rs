@@ -16,9 +16,14 @@ impl RobotPhaseBehavior for BoundaryAnalysisPhase {
         // Check if both robots are in boundary analysis phase
         let partner = context.all_robots.iter().find(|r| r.state.id == robot_state.partner_id).unwrap();
         if partner.state.phase == RobotPhase::BoundaryAnalysis {
-            // Use rotation-based analysis
-            let rotation_analysis = Self::analyze_boundary_by_rotation(robot_state, partner);
-            
+            // Prefer the closing-turn-aware winding number, since the robots
+            // rendezvous mid-boundary rather than literally closing their loop;
+            // fall back to the plain rotation difference if that's inconclusive.
+            let rotation_analysis = match Self::analyze_boundary_by_winding_number(robot_state, partner) {
+                BoundaryAnalysisResult::Incomplete => Self::analyze_boundary_by_rotation(robot_state, partner),
+                result => result,
+            };
+
             match rotation_analysis {
                 BoundaryAnalysisResult::Island => {
                     println!("Robot {} detected ISLAND (obstacle) via rotation analysis. Transitioning to IslandEscape to find exterior wall.", 
@@ -31,8 +36,27 @@ impl RobotPhaseBehavior for BoundaryAnalysisPhase {
                     PhaseTransition::Transition(RobotPhase::CentralScan)
                 },
                 BoundaryAnalysisResult::Incomplete => {
-                    println!("Robot {} rotation analysis incomplete. Continuing analysis.", robot_state.id);
-                    PhaseTransition::Continue
+                    // Rotation tracking didn't give a clean ±4-step answer between the
+                    // two robots. If this robot's own path has already closed on
+                    // itself, its own turning number alone should resolve it -- cheaper
+                    // than falling all the way back to flood-fill geometry.
+                    match Self::classify_single_tracer_loop_if_closed(robot_state)
+                        .or_else(|| Self::classify_sub_loop_islands_if_any(robot_state, context.global_map))
+                        .or_else(|| Self::analyze_boundary_by_path_fallback(robot_state, context.global_map))
+                    {
+                        Some(BoundaryAnalysisResult::Island) => {
+                            println!("Robot {} detected ISLAND via fallback classification.", robot_state.id);
+                            PhaseTransition::Transition(RobotPhase::IslandEscape)
+                        },
+                        Some(BoundaryAnalysisResult::ExteriorWall) => {
+                            println!("Robot {} detected EXTERIOR WALL via fallback classification.", robot_state.id);
+                            PhaseTransition::Transition(RobotPhase::CentralScan)
+                        },
+                        _ => {
+                            println!("Robot {} rotation analysis incomplete. Continuing analysis.", robot_state.id);
+                            PhaseTransition::Continue
+                        }
+                    }
                 }
             }
         } else {
@@ -63,4 +87,89 @@ impl BoundaryAnalysisPhase {
         
         BoundaryAnalyzer::analyze_boundary_by_rotation(robot0_rotation, robot1_rotation)
     }
+
+    /// Analyze boundary type using each robot's own winding number: its
+    /// accumulated `total_rotation_steps` plus the closing turn it would still
+    /// need to realign with the heading it started scouting with.
+    fn analyze_boundary_by_winding_number(robot_state: &RobotState, partner: &crate::robot_node::RobotNode) -> BoundaryAnalysisResult {
+        let robot0_state = if robot_state.id == ROBOT_LEFT_HAND { robot_state } else { &partner.state };
+        let robot1_state = if robot_state.id == ROBOT_RIGHT_HAND { robot_state } else { &partner.state };
+
+        let robot0_rotation = robot0_state.boundary_scout.as_ref().map(|s| s.total_rotation_steps);
+        let robot1_rotation = robot1_state.boundary_scout.as_ref().map(|s| s.total_rotation_steps);
+
+        BoundaryAnalyzer::analyze_completed_loop(
+            robot0_rotation,
+            Self::closing_turn(robot0_state),
+            robot1_rotation,
+            Self::closing_turn(robot1_state),
+        )
+    }
+
+    /// The signed turn `robot_state` would still need to realign its current
+    /// heading with the heading it started its current scouting leg facing.
+    /// Zero if there's no recorded starting direction yet.
+    fn closing_turn(robot_state: &RobotState) -> i32 {
+        let scout = match robot_state.boundary_scout.as_ref() {
+            Some(s) => s,
+            None => return 0,
+        };
+        let starting_heading = match scout.initial_scouting_direction.and_then(|d| Direction::from_vector(d.x, d.y)) {
+            Some(dir) => dir,
+            None => return 0,
+        };
+        let current_heading = Direction::from_rad(robot_state.pose.orientation_rad);
+        BoundaryAnalyzer::closing_turn_steps(current_heading, starting_heading)
+    }
+
+    /// If this robot's own boundary path has already closed on itself, tries
+    /// to classify it from its own accumulated turning number alone, with no
+    /// partner data required. `None` if the path isn't closed yet or the
+    /// turning number doesn't resolve cleanly (see `classify_single_tracer_loop`).
+    fn classify_single_tracer_loop_if_closed(robot_state: &RobotState) -> Option<BoundaryAnalysisResult> {
+        let scout = robot_state.boundary_scout.as_ref()?;
+        if !BoundaryAnalyzer::is_boundary_closed_loop(&scout.path) {
+            return None;
+        }
+        let total_rotation_steps = scout.total_rotation_steps + Self::closing_turn(robot_state);
+        match BoundaryAnalyzer::classify_single_tracer_loop(total_rotation_steps, scout.tracing_direction) {
+            BoundaryAnalysisResult::Incomplete => None,
+            result => Some(result),
+        }
+    }
+
+    /// Splits this robot's own scouting path into its closed sub-loops and
+    /// reports whether any of them is an island -- unlike
+    /// `classify_single_tracer_loop_if_closed`/`analyze_boundary_by_path_fallback`,
+    /// this doesn't require the *whole* path to be closed, so a single mission
+    /// that circled more than one disjoint obstacle before rendezvousing with
+    /// its partner still surfaces every island it passed, not just whichever
+    /// loop happens to join the path's first and last point.
+    fn classify_sub_loop_islands_if_any(robot_state: &RobotState, global_map: &GridMap) -> Option<BoundaryAnalysisResult> {
+        let scout = robot_state.boundary_scout.as_ref()?;
+        let sub_loops = BoundaryAnalyzer::classify_sub_loops(&scout.path, global_map, scout.tracing_direction);
+        let islands: Vec<&LoopWinding> = sub_loops.iter()
+            .filter(|winding| winding.result == BoundaryAnalysisResult::Island)
+            .collect();
+        if islands.is_empty() {
+            return None;
+        }
+        println!(
+            "Robot {} found {} disjoint island sub-loop(s) this mission (enclosed cells: {:?}).",
+            robot_state.id,
+            islands.len(),
+            islands.iter().map(|w| w.enclosed_cells).collect::<Vec<_>>()
+        );
+        Some(BoundaryAnalysisResult::Island)
+    }
+
+    /// Fall back to flood-fill geometry when rotation tracking is inconclusive.
+    /// Only meaningful once the traced path is actually a closed loop.
+    fn analyze_boundary_by_path_fallback(robot_state: &RobotState, global_map: &GridMap) -> Option<BoundaryAnalysisResult> {
+        let path = &robot_state.boundary_scout.as_ref()?.path;
+        if !BoundaryAnalyzer::is_boundary_closed_loop(path) {
+            return None;
+        }
+        Some(BoundaryAnalyzer::analyze_boundary_by_path(path, global_map))
+    }
 }
\ No newline at end of file
@@ -4,6 +4,8 @@ use crate::types::*;
 use crate::constants::*;
 use crate::robot_node::phase_trait::*;
 use crate::robot_node::wall_following::{WallFollower, RotationTracker};
+use crate::geometry;
+use crate::path_planner::path_planner::{self, UnexploredPolicy};
 
 /// Phase 2: Iterative boundary scouting with exponentially increasing depth
 #[derive(Debug, Clone)]
@@ -52,14 +54,20 @@ impl RobotPhaseBehavior for BoundaryScoutingPhase {
 
 impl BoundaryScoutingPhase {
     fn initialize_boundary_scout(robot_state: &mut RobotState) {
-        // Determine tracing direction based on which way robot will turn
-        // This is temporary - we'll determine the actual direction after first move
-        let tracing_direction = if robot_state.id == ROBOT_LEFT_HAND { 
-            RIGHT_HAND_RULE  // Will be set correctly after first move
-        } else { 
-            LEFT_HAND_RULE   // Will be set correctly after first move
+        // An explicit `preferred_wall_follow` is honored as-is for the whole
+        // mission (see `execute_forward_scouting`'s first-move handling, which
+        // skips the turn-away auto-correction below when one is set). With no
+        // preference, guess based on which way robot will turn -- this is
+        // temporary, and gets corrected after the first move.
+        let tracing_direction = match robot_state.preferred_wall_follow {
+            Some(rule) => rule.tracing_direction(),
+            None => if robot_state.id == ROBOT_LEFT_HAND {
+                RIGHT_HAND_RULE
+            } else {
+                LEFT_HAND_RULE
+            },
         };
-        
+
         robot_state.boundary_scout = Some(BoundaryScoutState {
             tracing_direction,
             steps_taken: 0,
@@ -75,46 +83,82 @@ impl BoundaryScoutingPhase {
                  robot_state.id, tracing_direction);
     }
 
+    /// Returns the robot to the start of the current scouting leg. Prefers the
+    /// shortest known-free route through the robot's own map (via the shared
+    /// `path_planner::astar_with_policy_and_connectivity`, the same planner
+    /// `FrontierExplorationPhase` and `Goal::ReachCell` goals use) over
+    /// literally retracing `scout.path`, since an outbound leg that looped or
+    /// backtracked makes the literal retrace far longer than necessary -- and
+    /// re-planning every tick means a newly discovered wall can never leave a
+    /// stale route routing through it. Falls back to retracing when no route
+    /// exists yet (e.g. the leg start isn't reachable through cells the robot
+    /// has actually seen).
     fn handle_return_journey(robot_state: &mut RobotState, scout_n: u32) -> PhaseTransition {
-        if let Some(scout) = robot_state.boundary_scout.as_ref() {
-            let path_length = scout.path.len();
-            if scout.steps_taken_this_scouting_mission + 1 < path_length as u32 {
-                // Continue returning
-                let target_index = path_length - 2 - scout.steps_taken_this_scouting_mission as usize;
-                let next_pos = scout.path[target_index];
-                
-                println!("Robot {} returns to ({}, {}). Path length remaining: {}", 
-                         robot_state.id, next_pos.x, next_pos.y, 
-                         path_length - 1 - scout.steps_taken_this_scouting_mission as usize);
-                
-                let prev_pos = robot_state.pose.position;
-                robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
-                robot_state.pose.position = next_pos;
-                
-                if let Some(scout) = robot_state.boundary_scout.as_mut() {
-                    scout.steps_taken_this_scouting_mission += 1;
-                }
-                
-                PhaseTransition::Continue
-            } else {
-                // Robot has returned to the start of the leg
-                println!("Robot {} completed return scan. Doubling scout_depth_n ({} -> {}) and starting next leg.", 
-                         robot_state.id, scout_n, scout_n * 2);
-                robot_state.scout_depth_n *= 2;
-                
-                if let Some(scout) = robot_state.boundary_scout.as_mut() {
-                    scout.steps_taken_this_scouting_mission = 0;
-                    scout.returning = false;
-                    scout.path.clear();
-                    scout.path.push(robot_state.pose.position);
-                    scout.first_move = true;
-                }
-                
-                PhaseTransition::Continue
+        let leg_start = match robot_state.boundary_scout.as_ref().and_then(|s| s.path.first().copied()) {
+            Some(p) => p,
+            None => return PhaseTransition::Continue,
+        };
+
+        if robot_state.pose.position == leg_start {
+            return Self::finish_return_leg(robot_state, scout_n);
+        }
+
+        let next_pos = path_planner::astar_with_policy_and_connectivity(
+            &robot_state.map, robot_state.pose.position, leg_start, UnexploredPolicy::Blocked, robot_state.connectivity,
+        )
+            .and_then(|path| path.get(1).copied())
+            .or_else(|| Self::retrace_next_step(robot_state));
+
+        let next_pos = match next_pos {
+            Some(p) => p,
+            None => {
+                println!("Robot {} has no route back to ({}, {}), staying put.",
+                         robot_state.id, leg_start.x, leg_start.y);
+                return PhaseTransition::Continue;
             }
-        } else {
-            PhaseTransition::Continue
+        };
+
+        println!("Robot {} returns to ({}, {}).", robot_state.id, next_pos.x, next_pos.y);
+
+        let prev_pos = robot_state.pose.position;
+        robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
+        robot_state.pose.position = next_pos;
+
+        if let Some(scout) = robot_state.boundary_scout.as_mut() {
+            scout.steps_taken_this_scouting_mission += 1;
+        }
+
+        PhaseTransition::Continue
+    }
+
+    /// Literal cell-by-cell retrace of `scout.path`, kept as a fallback for when
+    /// A* finds no route (e.g. the leg start became unreachable in the partial map).
+    fn retrace_next_step(robot_state: &RobotState) -> Option<Point> {
+        let scout = robot_state.boundary_scout.as_ref()?;
+        let path_length = scout.path.len();
+        let steps = scout.steps_taken_this_scouting_mission as usize;
+        if steps + 1 >= path_length {
+            return None;
         }
+        Some(scout.path[path_length - 2 - steps])
+    }
+
+    /// The robot has arrived back at the start of the leg: double the scout depth
+    /// and begin the next outbound leg.
+    fn finish_return_leg(robot_state: &mut RobotState, scout_n: u32) -> PhaseTransition {
+        println!("Robot {} completed return scan. Doubling scout_depth_n ({} -> {}) and starting next leg.",
+                 robot_state.id, scout_n, scout_n * 2);
+        robot_state.scout_depth_n *= 2;
+
+        if let Some(scout) = robot_state.boundary_scout.as_mut() {
+            scout.steps_taken_this_scouting_mission = 0;
+            scout.returning = false;
+            scout.path.clear();
+            scout.path.push(robot_state.pose.position);
+            scout.first_move = true;
+        }
+
+        PhaseTransition::Continue
     }
 
     fn execute_forward_scouting(robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
@@ -124,6 +168,9 @@ impl BoundaryScoutingPhase {
             return PhaseTransition::Continue;
         };
 
+        let partner = context.all_robots.iter().find(|r| r.state.id == robot_state.partner_id).unwrap();
+        let other_positions = [partner.state.pose.position];
+
         let next = if first_move {
             // Check if we have a stored initial scouting direction
             let stored_direction = robot_state.boundary_scout.as_ref().and_then(|s| s.initial_scouting_direction);
@@ -135,24 +182,24 @@ impl BoundaryScoutingPhase {
                     y: robot_state.pose.position.y + direction.y,
                 };
                 
-                if WallFollower::is_position_valid_and_empty(next, context.global_map) {
+                if WallFollower::is_position_valid_and_empty(next, &robot_state.map) {
                     Some(next)
                 } else {
                     // Fallback to wall following if stored direction is blocked
-                    WallFollower::wall_follow_step(
+                    WallFollower::wall_follow_step_avoiding(
                         robot_state.pose.position,
                         robot_state.pose.orientation_rad,
-                        context.global_map,
+                        &robot_state.map,
                         tracing_direction,
+                        &other_positions,
                     )
                 }
             } else {
                 // First time - calculate direction based on partner position
-                let partner = context.all_robots.iter().find(|r| r.state.id == robot_state.partner_id).unwrap();
                 let next_pos = WallFollower::wall_follow_step_first_move(
                     robot_state.pose.position,
                     robot_state.pose.orientation_rad,
-                    context.global_map,
+                    &robot_state.map,
                     partner.state.pose.position,
                 );
                 
@@ -180,9 +227,14 @@ impl BoundaryScoutingPhase {
                         LEFT_HAND_RULE
                     };
                     
+                    let preferred = robot_state.preferred_wall_follow;
                     if let Some(scout) = robot_state.boundary_scout.as_mut() {
                         scout.initial_scouting_direction = Some(direction);
-                        scout.tracing_direction = correct_tracing_direction;
+                        // An explicit preference is honored for the whole mission rather
+                        // than overwritten by the turn-away-from-partner guess.
+                        scout.tracing_direction = preferred
+                            .map(|rule| rule.tracing_direction())
+                            .unwrap_or(correct_tracing_direction);
                     }
                 }
                 
@@ -191,11 +243,15 @@ impl BoundaryScoutingPhase {
             
             next_pos
         } else {
-            WallFollower::wall_follow_step(
+            WallFollower::wall_follow_step_with_repulsion(
                 robot_state.pose.position,
                 robot_state.pose.orientation_rad,
-                context.global_map,
+                &robot_state.map,
                 tracing_direction,
+                &other_positions,
+                context.pheromone,
+                context.global_map.width,
+                robot_state.momentum_prob,
             )
         };
 
@@ -212,9 +268,9 @@ impl BoundaryScoutingPhase {
             
             let prev_pos = robot_state.pose.position;
             let prev_orientation = robot_state.pose.orientation_rad;
-            robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
+            robot_state.pose.orientation_rad = WallFollower::update_orientation_wrapped(prev_pos, next_pos, &robot_state.map);
             robot_state.pose.position = next_pos;
-            
+
             // Track rotation change for boundary analysis
             let rotation_steps = RotationTracker::calculate_rotation_steps(prev_orientation, robot_state.pose.orientation_rad);
             if let Some(scout) = robot_state.boundary_scout.as_mut() {
@@ -230,10 +286,9 @@ impl BoundaryScoutingPhase {
                 scout.first_move = false;
             }
 
-            // Check for rendezvous during active scouting leg
-            let partner = context.all_robots.iter().find(|r| r.state.id == robot_state.partner_id).unwrap();
-            
-            if !first_move && Self::within_comm_range(&robot_state.pose.position, &partner.state.pose.position) 
+            // Check for rendezvous during active scouting leg, measured continuously
+            // (true Euclidean distance) rather than per-cell Manhattan distance.
+            if !first_move && Self::within_comm_range(&robot_state.pose, &partner.state.pose)
                && robot_state.pose.position != partner.state.pose.position {
                 println!("Robot {} rendezvous with partner {} during scouting leg. Transitioning to BOUNDARY_ANALYSIS.", 
                          robot_state.id, partner.state.id);
@@ -248,8 +303,9 @@ impl BoundaryScoutingPhase {
         }
     }
 
-    /// Returns true if two positions are within communication range
-    fn within_comm_range(a: &Point, b: &Point) -> bool {
-        (a.x - b.x).abs() + (a.y - b.y).abs() <= COMMUNICATION_RANGE
+    /// Returns true if two poses are within communication range, using true
+    /// Euclidean center-to-center distance rather than a per-cell Manhattan test.
+    fn within_comm_range(a: &Pose, b: &Pose) -> bool {
+        geometry::closest_distance(*a, *b) <= COMMUNICATION_RANGE as f64
     }
 }
\ No newline at end of file
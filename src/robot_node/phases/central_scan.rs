@@ -125,11 +125,15 @@ impl CentralScanPhase {
     
     /// Reset boundary scout state for virtual boundary tracing (no scouting missions)
     fn reset_boundary_scout_for_virtual_tracing(robot_state: &mut RobotState) {
-        // Determine tracing direction based on robot ID (opposite directions)
-        let tracing_direction = if robot_state.id == ROBOT_LEFT_HAND { 
-            RIGHT_HAND_RULE
-        } else { 
-            LEFT_HAND_RULE 
+        // Honor an explicit hand-rule preference; otherwise fall back to
+        // robot ID giving the two partners opposite directions.
+        let tracing_direction = match robot_state.preferred_wall_follow {
+            Some(rule) => rule.tracing_direction(),
+            None => if robot_state.id == ROBOT_LEFT_HAND {
+                RIGHT_HAND_RULE
+            } else {
+                LEFT_HAND_RULE
+            },
         };
         
         // For central scan, we don't need scouting missions - just trace until rendezvous
@@ -201,9 +205,9 @@ impl CentralScanPhase {
         
         if let Some(next_pos) = next_pos {
             let prev_pos = robot_state.pose.position;
-            robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
+            robot_state.pose.orientation_rad = WallFollower::update_orientation_wrapped(prev_pos, next_pos, &robot_state.map);
             robot_state.pose.position = next_pos;
-            
+
             println!("Robot {} virtual trace: moved to ({}, {})", robot_state.id, next_pos.x, next_pos.y);
             
             // Update boundary scout state
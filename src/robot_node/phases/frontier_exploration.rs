@@ -0,0 +1,115 @@
+/// Frontier exploration phase implementation
+
+use crate::types::*;
+use crate::robot_node::phase_trait::*;
+use crate::robot_node::wall_following::WallFollower;
+use crate::map_manager::map_manager;
+use crate::path_planner::path_planner::{self, UnexploredPolicy};
+
+/// Phase: Frontier Exploration - covers open interiors by heading for the
+/// nearest unclaimed frontier cluster, as an alternative to wall-tracing.
+#[derive(Debug, Clone)]
+pub struct FrontierExplorationPhase;
+
+impl RobotPhaseBehavior for FrontierExplorationPhase {
+    fn execute(&mut self, robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
+        let frontiers = map_manager::cluster_frontiers(&robot_state.map);
+
+        if frontiers.is_empty() {
+            println!("Robot {} found no frontiers left -- reachable area fully mapped", robot_state.id);
+            robot_state.frontier_exploration = None;
+            return PhaseTransition::Transition(RobotPhase::Idle);
+        }
+
+        let partner_target = context.all_robots.iter()
+            .find(|r| r.state.id == robot_state.partner_id)
+            .and_then(|partner| partner.state.frontier_exploration.as_ref())
+            .and_then(|state| state.target);
+
+        let current_target = robot_state.frontier_exploration.as_ref().and_then(|state| state.target);
+
+        // (Re)pick a target if we don't have one, the one we had has since been
+        // explored out from under us, or the partner just claimed it too.
+        let stale = match current_target {
+            Some(target) => !frontiers.iter().any(|f| f.cells.contains(&target)) || partner_target == Some(target),
+            None => true,
+        };
+
+        let target = if stale {
+            let chosen = Self::choose_frontier(robot_state, &frontiers, partner_target);
+            robot_state.frontier_exploration = Some(FrontierExplorationState { target: chosen });
+            chosen
+        } else {
+            current_target
+        };
+
+        let target = match target {
+            Some(target) => target,
+            None => {
+                println!("Robot {} found no reachable unclaimed frontier, waiting", robot_state.id);
+                return PhaseTransition::Continue;
+            }
+        };
+
+        if robot_state.pose.position == target {
+            robot_state.frontier_exploration = Some(FrontierExplorationState { target: None });
+            return PhaseTransition::Continue;
+        }
+
+        let next_pos = path_planner::astar_with_policy_and_connectivity(
+            &robot_state.map, robot_state.pose.position, target, UnexploredPolicy::Blocked, robot_state.connectivity,
+        ).and_then(|path| path.get(1).copied());
+
+        match next_pos {
+            Some(next_pos) => {
+                let prev_pos = robot_state.pose.position;
+                robot_state.pose.orientation_rad = WallFollower::update_orientation_allowing_diagonal(prev_pos, next_pos);
+                robot_state.pose.position = next_pos;
+                println!("Robot {} heading to frontier ({}, {}), now at ({}, {})",
+                         robot_state.id, target.x, target.y, next_pos.x, next_pos.y);
+                PhaseTransition::Continue
+            }
+            None => {
+                println!("Robot {} lost its path to frontier ({}, {}), re-planning next tick", robot_state.id, target.x, target.y);
+                robot_state.frontier_exploration = Some(FrontierExplorationState { target: None });
+                PhaseTransition::Continue
+            }
+        }
+    }
+
+    fn phase_type(&self) -> RobotPhase {
+        RobotPhase::FrontierExploration
+    }
+}
+
+impl FrontierExplorationPhase {
+    /// Picks the lowest real-path-cost unclaimed frontier cell for this
+    /// robot: each cluster is reduced to its nearest-to-the-robot member cell
+    /// (guaranteed to be an actual `CellState::Empty` frontier cell, unlike
+    /// `Frontier::centroid`, which is just an averaged position that can land
+    /// on an obstacle or an unexplored cell in a concave cluster and make
+    /// that whole frontier silently unreachable), then candidates are costed
+    /// with A* over known-free cells rather than
+    /// `map_manager::assign_frontiers`'s Manhattan-distance approximation,
+    /// since a robot's own map may have corridors a straight-line estimate
+    /// badly underrates.
+    fn choose_frontier(robot_state: &RobotState, frontiers: &[Frontier], partner_target: Option<Point>) -> Option<Point> {
+        frontiers.iter()
+            .filter_map(|f| Self::nearest_cell(robot_state.pose.position, f))
+            .filter(|&cell| Some(cell) != partner_target)
+            .filter_map(|cell| {
+                path_planner::astar_with_policy_and_connectivity(
+                    &robot_state.map, robot_state.pose.position, cell, UnexploredPolicy::Blocked, robot_state.connectivity,
+                ).map(|path| (cell, path.len()))
+            })
+            .min_by_key(|&(_, cost)| cost)
+            .map(|(cell, _)| cell)
+    }
+
+    /// The cell of `frontier.cells` closest (Manhattan distance) to `from`,
+    /// used as a real, reachable stand-in for the cluster's averaged centroid.
+    fn nearest_cell(from: Point, frontier: &Frontier) -> Option<Point> {
+        frontier.cells.iter().copied()
+            .min_by_key(|cell| (cell.x - from.x).abs() + (cell.y - from.y).abs())
+    }
+}
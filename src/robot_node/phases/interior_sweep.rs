@@ -4,6 +4,7 @@ use crate::types::*;
 use crate::constants::*;
 use crate::robot_node::phase_trait::*;
 use crate::robot_node::wall_following::WallFollower;
+use crate::map_manager::map_manager;
 
 /// Phase 5: Interior Sweep - systematic coordinated exploration of interior
 #[derive(Debug, Clone)]
@@ -12,27 +13,20 @@ pub struct InteriorSweepPhase;
 impl RobotPhaseBehavior for InteriorSweepPhase {
     fn execute(&mut self, robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
         println!("Robot {} executing interior sweep", robot_state.id);
-        
-        // Ensure both robots are in interior sweep phase before proceeding
-        let partner = context.all_robots.iter().find(|r| r.state.id == robot_state.partner_id).unwrap();
-        if partner.state.phase != RobotPhase::InteriorSweep {
-            println!("Robot {} waiting for partner to enter interior sweep phase", robot_state.id);
-            return PhaseTransition::Continue;
-        }
-        
+
         // Check if exploration is complete
         if Self::is_exploration_complete(robot_state) {
             println!("Robot {} completed interior exploration - no more unexplored areas!", robot_state.id);
             return PhaseTransition::Transition(RobotPhase::Idle);
         }
-        
+
         // Initialize interior sweep state if needed
         if robot_state.loop_analysis_data.is_none() {
-            Self::initialize_interior_sweep(robot_state, partner);
+            Self::initialize_interior_sweep(robot_state);
         }
-        
+
         // Execute coordinated sweep movement
-        Self::execute_sweep_movement(robot_state, partner, context.global_map)
+        Self::execute_sweep_movement(robot_state, context)
     }
 
     fn phase_type(&self) -> RobotPhase {
@@ -42,16 +36,16 @@ impl RobotPhaseBehavior for InteriorSweepPhase {
 
 impl InteriorSweepPhase {
     /// Initialize the interior sweep by setting up sweep parameters
-    fn initialize_interior_sweep(robot_state: &mut RobotState, _partner: &crate::robot_node::RobotNode) {
+    fn initialize_interior_sweep(robot_state: &mut RobotState) {
         println!("Robot {} initializing interior sweep", robot_state.id);
-        
+
         // Find the boundary that was just traced
         let boundary_path = if let Some(ref scout_state) = robot_state.boundary_scout {
             scout_state.path.clone()
         } else {
             Vec::new()
         };
-        
+
         robot_state.loop_analysis_data = Some(LoopAnalysisData {
             path_traced: boundary_path,
             total_angular_displacement: 0.0,
@@ -59,227 +53,162 @@ impl InteriorSweepPhase {
             loop_closed: Some(true),
             total_loop_length: None,
             midpoint_direction: None,
-            target_position: None,
         });
-        
+
         println!("Robot {} interior sweep initialized", robot_state.id);
     }
-    
+
+    /// Every robot currently in `InteriorSweep`, including `robot_state`
+    /// itself -- the set of participants an auction round and a "has
+    /// everyone reached their goal" check are scoped to, in place of a single
+    /// hard-coded `partner_id`.
+    fn participants(robot_state: &RobotState, context: &PhaseContext) -> Vec<u8> {
+        let mut ids: Vec<u8> = context.all_robots.iter()
+            .filter(|r| r.state.phase == RobotPhase::InteriorSweep)
+            .map(|r| r.state.id)
+            .collect();
+        if !ids.contains(&robot_state.id) {
+            ids.push(robot_state.id);
+        }
+        ids
+    }
+
     /// Execute coordinated sweep movement along the frontier
-    fn execute_sweep_movement(robot_state: &mut RobotState, partner: &crate::robot_node::RobotNode, global_map: &GridMap) -> PhaseTransition {
-        // Find frontier cells (explored cells adjacent to unexplored)
-        let frontier_cells = Self::find_frontier_cells(&robot_state.map);
-        
-        if frontier_cells.is_empty() {
-            println!("Robot {} found no frontier cells - exploration complete", robot_state.id);
+    fn execute_sweep_movement(robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
+        // Cluster frontier cells from the shared ground-truth map, rather than
+        // each robot's own partial view, so every participant's auction bid
+        // is computed against the same candidate set.
+        let frontiers = map_manager::cluster_frontiers(context.global_map);
+
+        if frontiers.is_empty() {
+            println!("Robot {} found no frontier clusters - exploration complete", robot_state.id);
             return PhaseTransition::Transition(RobotPhase::Idle);
         }
-        
-        // Check if we're in a sweep iteration or need to start a new one
+
         let current_pos = robot_state.pose.position;
-        
-        // Initialize sweep state if needed
-        if robot_state.loop_analysis_data.as_ref().unwrap().target_position.is_none() {
-            Self::initialize_sweep_iteration(robot_state, partner, &frontier_cells);
+        let participants = Self::participants(robot_state, context);
+
+        // (Re)run the auction if this robot doesn't hold a cluster still
+        // present in the current frontier set.
+        let stale = match robot_state.assigned_frontier_goal {
+            Some(goal) => !frontiers.iter().any(|f| f.centroid == goal),
+            None => true,
+        };
+        if stale {
+            Self::run_auction(robot_state, context, &participants, &frontiers);
         }
-        
-        // Get target position for this sweep iteration
-        let target_pos = robot_state.loop_analysis_data.as_ref().unwrap().target_position.unwrap();
-        
-        // Check if we've reached our target position
+
+        let target_pos = match robot_state.assigned_frontier_goal {
+            Some(goal) => goal,
+            None => {
+                println!("Robot {} won no frontier cluster in the auction, waiting", robot_state.id);
+                return PhaseTransition::Continue;
+            }
+        };
+
+        // Check if we've reached our assigned goal
         if current_pos == target_pos {
-            println!("Robot {} reached target position ({}, {})", robot_state.id, target_pos.x, target_pos.y);
-            
-            // Check if partner has also reached their target
-            if Self::both_robots_reached_targets(robot_state, partner) {
-                println!("Robot {} - both robots completed sweep iteration, moving inward", robot_state.id);
-                Self::start_next_sweep_iteration(robot_state, partner, &frontier_cells);
+            println!("Robot {} reached assigned goal ({}, {})", robot_state.id, target_pos.x, target_pos.y);
+
+            if Self::all_participants_reached_goals(context, &participants) {
+                println!("Robot {} - all participants completed their sweep leg, moving inward", robot_state.id);
+                robot_state.assigned_frontier_goal = None;
                 return PhaseTransition::Continue;
             } else {
-                // Wait for partner to reach their target
-                println!("Robot {} waiting for partner to complete sweep leg", robot_state.id);
+                println!("Robot {} waiting for other participants to complete their sweep leg", robot_state.id);
                 return PhaseTransition::Continue;
             }
         }
-        
-        // Move toward target position using frontier-following
-        if let Some(next_pos) = Self::move_toward_target_along_frontier(robot_state, target_pos, global_map) {
+
+        // Move toward the assigned goal by descending a wavefront navigation
+        // function recomputed fresh each tick, rather than hill-climbing
+        // Manhattan distance to a single point -- guarantees monotonic
+        // progress and can't get stuck in a concave-obstacle local minimum.
+        if let Some(next_pos) = Self::descend_wavefront(robot_state, target_pos, context.global_map) {
             robot_state.pose.position = next_pos;
-            robot_state.pose.orientation_rad = WallFollower::update_orientation(current_pos, next_pos);
-            
-            println!("Robot {} swept to ({}, {}) toward target ({}, {})", 
+            robot_state.pose.orientation_rad = WallFollower::update_orientation_allowing_diagonal(current_pos, next_pos);
+
+            println!("Robot {} swept to ({}, {}) toward target ({}, {})",
                      robot_state.id, next_pos.x, next_pos.y, target_pos.x, target_pos.y);
-            
+
             PhaseTransition::Continue
         } else {
             println!("Robot {} cannot reach target - exploration complete", robot_state.id);
             PhaseTransition::Transition(RobotPhase::Idle)
         }
     }
-    
-    /// Find frontier cells (explored cells adjacent to unexplored cells)
-    fn find_frontier_cells(map: &GridMap) -> Vec<Point> {
-        let mut frontier = Vec::new();
-        let directions = [NORTH, SOUTH, EAST, WEST];
-        
-        for y in 0..map.height {
-            for x in 0..map.width {
-                let idx = y * map.width + x;
-                if map.cells[idx] == CellState::Empty {
-                    let pos = Point { x: x as i32, y: y as i32 };
-                    
-                    // Check if adjacent to unexplored
-                    for (dx, dy) in &directions {
-                        let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
-                        if neighbor.x >= 0 && neighbor.x < map.width as i32 && 
-                           neighbor.y >= 0 && neighbor.y < map.height as i32 {
-                            let neighbor_idx = (neighbor.y as usize) * map.width + (neighbor.x as usize);
-                            if map.cells[neighbor_idx] == CellState::Unexplored {
-                                frontier.push(pos);
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        frontier
-    }
-    
-    /// Initialize a new sweep iteration by assigning target positions
-    fn initialize_sweep_iteration(robot_state: &mut RobotState, _partner: &crate::robot_node::RobotNode, frontier_cells: &[Point]) {
-        println!("Robot {} initializing new sweep iteration", robot_state.id);
-        
-        // Find the frontier boundary as a connected path
-        let frontier_path = Self::trace_frontier_boundary(frontier_cells);
-        
-        if frontier_path.len() < 2 {
-            // Not enough frontier for coordinated sweep
-            if let Some(&first_frontier) = frontier_cells.first() {
-                robot_state.loop_analysis_data.as_mut().unwrap().target_position = Some(first_frontier);
-            }
-            return;
-        }
-        
-        // Assign opposite ends of the frontier, but pick reasonable targets
-        let current_pos = robot_state.pose.position;
-        
-        // Find the best target for this robot based on its role and current position
-        let target = if robot_state.id == ROBOT_LEFT_HAND {
-            // Robot 0 goes to the leftmost frontier point that's not too far
-            frontier_path.iter()
-                .take(frontier_path.len() / 2 + 1) // Consider first half + middle
-                .min_by_key(|&&p| Self::manhattan_distance(current_pos, p))
-                .copied()
-                .unwrap_or(frontier_path[0])
-        } else {
-            // Robot 1 goes to the rightmost frontier point that's not too far  
-            frontier_path.iter()
-                .skip(frontier_path.len() / 2) // Consider second half
-                .min_by_key(|&&p| Self::manhattan_distance(current_pos, p))
-                .copied()
-                .unwrap_or(frontier_path[frontier_path.len() - 1])
-        };
-        
-        robot_state.loop_analysis_data.as_mut().unwrap().target_position = Some(target);
-        
-        println!("Robot {} assigned target position ({}, {})", robot_state.id, target.x, target.y);
-    }
-    
-    /// Trace the frontier boundary to create a connected path
-    fn trace_frontier_boundary(frontier_cells: &[Point]) -> Vec<Point> {
-        if frontier_cells.is_empty() {
-            return Vec::new();
-        }
-        
-        if frontier_cells.len() == 1 {
-            return frontier_cells.to_vec();
+
+    /// Runs `PhaseContext::auction_frontier_clusters` over every participating
+    /// robot and adopts this robot's own award, if it won one -- a single
+    /// auction resolves every participant's goal at once, so this is
+    /// redundant work when called from each robot's own `execute`, but
+    /// deterministic given the same `participants`/`frontiers` inputs.
+    fn run_auction(robot_state: &mut RobotState, context: &PhaseContext, participants: &[u8], frontiers: &[Frontier]) {
+        let assignments = context.auction_frontier_clusters(participants, frontiers);
+        robot_state.assigned_frontier_goal = assignments.get(&robot_state.id).copied();
+        if let Some(goal) = robot_state.assigned_frontier_goal {
+            println!("Robot {} won frontier cluster at ({}, {}) in the auction", robot_state.id, goal.x, goal.y);
         }
-        
-        // Find the leftmost and rightmost frontier cells for better separation
-        let mut sorted_frontier = frontier_cells.to_vec();
-        
-        // Sort first by X coordinate (left to right), then by Y 
-        sorted_frontier.sort_by_key(|p| (p.x, p.y));
-        
-        // Return the sorted path from leftmost to rightmost
-        sorted_frontier
-    }
-    
-    /// Check if both robots have reached their target positions
-    fn both_robots_reached_targets(robot_state: &RobotState, partner: &crate::robot_node::RobotNode) -> bool {
-        let robot_reached = robot_state.loop_analysis_data.as_ref()
-            .and_then(|data| data.target_position)
-            .map(|target| robot_state.pose.position == target)
-            .unwrap_or(false);
-            
-        let partner_reached = partner.state.loop_analysis_data.as_ref()
-            .and_then(|data| data.target_position)
-            .map(|target| partner.state.pose.position == target)
-            .unwrap_or(false);
-            
-        robot_reached && partner_reached
     }
-    
-    /// Start the next sweep iteration by moving inward
-    fn start_next_sweep_iteration(robot_state: &mut RobotState, _partner: &crate::robot_node::RobotNode, _frontier_cells: &[Point]) {
-        println!("Robot {} starting next sweep iteration (moving inward)", robot_state.id);
-        
-        // Clear current target to trigger re-initialization
-        robot_state.loop_analysis_data.as_mut().unwrap().target_position = None;
-        
-        // Find new frontier after exploration progress
-        // This will be handled in the next call to execute_sweep_movement
+
+    /// True once every participant that won a cluster has reached it.
+    /// A participant that won nothing this round (clusters ran out before
+    /// its turn) doesn't block the others.
+    fn all_participants_reached_goals(context: &PhaseContext, participants: &[u8]) -> bool {
+        participants.iter().all(|&id| {
+            context.all_robots.iter()
+                .find(|r| r.state.id == id)
+                .map(|r| match r.state.assigned_frontier_goal {
+                    Some(goal) => r.state.pose.position == goal,
+                    None => true,
+                })
+                .unwrap_or(true)
+        })
     }
-    
-    /// Move toward target position along the frontier
-    fn move_toward_target_along_frontier(robot_state: &RobotState, target: Point, global_map: &GridMap) -> Option<Point> {
-        let current_pos = robot_state.pose.position;
-        let directions = [NORTH, SOUTH, EAST, WEST];
-        
-        // Find the best direction towards target that follows frontier
-        let mut best_move = None;
-        let mut best_distance = i32::MAX;
-        
-        for (dx, dy) in &directions {
-            let next_pos = Point { x: current_pos.x + dx, y: current_pos.y + dy };
-            
-            // Check if move is valid and gets us closer to target
-            if Self::is_valid_move(next_pos, global_map) {
-                let distance = Self::manhattan_distance(next_pos, target);
-                if distance < best_distance {
-                    best_distance = distance;
-                    best_move = Some(next_pos);
-                }
-            }
+
+    /// One step of wavefront descent toward `target`: recomputes
+    /// `GridMap::compute_wavefront` seeded at `target` against the shared
+    /// `global_map` (not the robot's own map), then steps to whichever
+    /// 4-connected neighbor has the lowest
+    /// value (ties broken toward the robot's own hand-rule role -- left-hand
+    /// prefers the lower-indexed neighbor, right-hand the higher-indexed one
+    /// -- so the two robots' descents diverge instead of both breaking ties
+    /// identically). Recomputed fresh every tick, so there is no cached route
+    /// to invalidate.
+    fn descend_wavefront(robot_state: &RobotState, target: Point, global_map: &GridMap) -> Option<Point> {
+        const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        let wavefront = global_map.compute_wavefront(&[target]);
+        let current = robot_state.pose.position;
+
+        let mut candidates: Vec<(Point, i32)> = FOUR_NEIGHBORS.iter()
+            .map(|&(dx, dy)| Point { x: current.x + dx, y: current.y + dy })
+            .filter_map(|pos| global_map.coord_to_index(pos).map(|idx| (pos, wavefront[idx])))
+            .filter(|&(_, value)| value != i32::MAX)
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
         }
-        
-        best_move
-    }
-    
-    /// Check if a move is valid (not obstacle, within bounds)
-    fn is_valid_move(pos: Point, global_map: &GridMap) -> bool {
-        if pos.x < 0 || pos.x >= global_map.width as i32 || 
-           pos.y < 0 || pos.y >= global_map.height as i32 {
-            return false;
+
+        candidates.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.x.cmp(&b.0.x)).then(a.0.y.cmp(&b.0.y)));
+        let best_value = candidates[0].1;
+        let mut tied: Vec<Point> = candidates.into_iter().filter(|&(_, v)| v == best_value).map(|(p, _)| p).collect();
+
+        if robot_state.id != ROBOT_LEFT_HAND {
+            tied.reverse();
         }
-        
-        let idx = (pos.y as usize) * global_map.width + (pos.x as usize);
-        global_map.cells[idx] != CellState::Obstacle
-    }
-    
-    /// Calculate Manhattan distance between two points
-    fn manhattan_distance(a: Point, b: Point) -> i32 {
-        (a.x - b.x).abs() + (a.y - b.y).abs()
+
+        tied.into_iter().next()
     }
-    
+
     /// Check if exploration is complete (no more unexplored areas)
     fn is_exploration_complete(robot_state: &RobotState) -> bool {
         let unexplored_count = robot_state.map.cells.iter()
             .filter(|&&cell| cell == CellState::Unexplored)
             .count();
-        
+
         // Consider exploration complete if very few unexplored cells remain
         unexplored_count < 5
     }
-}
\ No newline at end of file
+}
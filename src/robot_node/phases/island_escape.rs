@@ -4,6 +4,7 @@ use crate::types::*;
 use crate::constants::*;
 use crate::robot_node::phase_trait::*;
 use crate::robot_node::wall_following::WallFollower;
+use crate::path_planner::path_planner::{self, UnexploredPolicy};
 
 /// Phase 4A: Island Escape - move away from detected island to find exterior wall
 #[derive(Debug, Clone)]
@@ -13,42 +14,41 @@ impl RobotPhaseBehavior for IslandEscapePhase {
     fn execute(&mut self, robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
         println!("Robot {} executing island escape", robot_state.id);
         
-        // Move in a straight line away from the island to find the exterior wall
+        // Navigate away from the island toward a point well past it, routing
+        // around interior obstacles with A* instead of bumping along a single
+        // straight-line heuristic direction that easily gets wedged on concave ones.
         if let Some(ref scout_state) = robot_state.boundary_scout {
             let island_center = Self::calculate_path_centroid(&scout_state.path, robot_state.pose.position);
-            let escape_direction = Self::calculate_escape_direction(robot_state.pose.position, island_center);
-            
-            // Try to move in the escape direction
-            let (dx, dy) = escape_direction;
-            let next_pos = Point {
-                x: robot_state.pose.position.x + dx,
-                y: robot_state.pose.position.y + dy,
-            };
-            
-            // Check bounds and obstacles
-            if WallFollower::is_position_valid_and_empty(next_pos, context.global_map) {
-                let prev_pos = robot_state.pose.position;
-                robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
+            let prev_pos = robot_state.pose.position;
+            let (dx, dy) = Self::calculate_escape_direction(prev_pos, island_center, robot_state.connectivity);
+            let target = Self::escape_target(prev_pos, (dx, dy), context.global_map);
+
+            let next_pos = path_planner::astar_with_policy_and_connectivity(context.global_map, prev_pos, target, UnexploredPolicy::Traversable, robot_state.connectivity)
+                .and_then(|path| path.get(1).copied());
+
+            if let Some(next_pos) = next_pos {
+                robot_state.pose.orientation_rad = WallFollower::update_orientation_allowing_diagonal(prev_pos, next_pos);
                 robot_state.pose.position = next_pos;
                 println!("Robot {} escaping island, moved to ({}, {})", robot_state.id, next_pos.x, next_pos.y);
-                
+
                 // After moving away, look for a new wall to start boundary scouting again
                 let forward_pos = Point {
                     x: next_pos.x + dx,
                     y: next_pos.y + dy,
                 };
-                
+
                 if !WallFollower::is_position_valid_and_empty(forward_pos, context.global_map) {
                     println!("Robot {} found new wall during island escape. Restarting boundary scouting.", robot_state.id);
                     robot_state.scout_depth_n = INITIAL_SCOUT_DEPTH;
                     robot_state.boundary_scout = None;
                     return PhaseTransition::Transition(RobotPhase::BoundaryScouting);
                 }
-                
+
                 PhaseTransition::Continue
             } else {
-                println!("Robot {} cannot move in escape direction, exploring alternatives", robot_state.id);
-                Self::try_alternative_escape_directions(robot_state, context.global_map);
+                println!("Robot {} could not plan an escape path, exploring alternatives", robot_state.id);
+                let connectivity = robot_state.connectivity;
+                Self::try_alternative_escape_directions(robot_state, context.global_map, connectivity);
                 PhaseTransition::Continue
             }
         } else {
@@ -79,32 +79,66 @@ impl IslandEscapePhase {
         }
     }
 
-    /// Calculate direction to escape from an island center
-    fn calculate_escape_direction(current_pos: Point, island_center: Point) -> (i32, i32) {
+    /// Calculate direction to escape from an island center. Under
+    /// `Connectivity::Eight`, emits a diagonal unit vector whenever the
+    /// centroid offset isn't axis-aligned, which is a more direct (and often
+    /// shorter) escape than snapping to the single larger-delta axis.
+    fn calculate_escape_direction(current_pos: Point, island_center: Point, connectivity: Connectivity) -> (i32, i32) {
         let dx = current_pos.x - island_center.x;
         let dy = current_pos.y - island_center.y;
-        
-        // Normalize to unit direction
-        if dx.abs() > dy.abs() {
-            (if dx > 0 { 1 } else { -1 }, 0)
-        } else {
-            (0, if dy > 0 { 1 } else { -1 })
+
+        let unit = |d: i32| if d > 0 { 1 } else if d < 0 { -1 } else { 0 };
+
+        match connectivity {
+            Connectivity::Eight => (unit(dx), unit(dy)),
+            Connectivity::Four => {
+                if dx.abs() > dy.abs() {
+                    (unit(dx), 0)
+                } else {
+                    (0, unit(dy))
+                }
+            }
         }
     }
 
-    /// Try alternative escape directions if primary direction is blocked
-    fn try_alternative_escape_directions(robot_state: &mut RobotState, global_map: &GridMap) {
-        let directions = [NORTH, EAST, SOUTH, WEST];
-        
-        for (dx, dy) in &directions {
+    /// A far point in the escape direction, clamped to the map, for A* to plan
+    /// a real route to -- rather than a single step the planner just re-derives
+    /// on the next tick anyway.
+    fn escape_target(current_pos: Point, (dx, dy): (i32, i32), map: &GridMap) -> Point {
+        let reach = map.width.max(map.height) as i32;
+        Point {
+            x: (current_pos.x + dx * reach).clamp(0, map.width as i32 - 1),
+            y: (current_pos.y + dy * reach).clamp(0, map.height as i32 - 1),
+        }
+    }
+
+    /// Try alternative escape directions if primary direction is blocked, in
+    /// rotational order. Tries all eight neighbors under `Connectivity::Eight`,
+    /// skipping any diagonal that would clip an obstacle corner.
+    fn try_alternative_escape_directions(robot_state: &mut RobotState, global_map: &GridMap, connectivity: Connectivity) {
+        let directions: &[(i32, i32)] = match connectivity {
+            Connectivity::Four => &[NORTH, EAST, SOUTH, WEST],
+            Connectivity::Eight => &EIGHT_NEIGHBORS,
+        };
+
+        for (dx, dy) in directions {
             let next_pos = Point {
                 x: robot_state.pose.position.x + dx,
                 y: robot_state.pose.position.y + dy,
             };
-            
+
+            if *dx != 0 && *dy != 0 {
+                let orthogonal_x = Point { x: robot_state.pose.position.x + dx, y: robot_state.pose.position.y };
+                let orthogonal_y = Point { x: robot_state.pose.position.x, y: robot_state.pose.position.y + dy };
+                if !WallFollower::is_position_valid_and_empty(orthogonal_x, global_map)
+                    || !WallFollower::is_position_valid_and_empty(orthogonal_y, global_map) {
+                    continue;
+                }
+            }
+
             if WallFollower::is_position_valid_and_empty(next_pos, global_map) {
                 let prev_pos = robot_state.pose.position;
-                robot_state.pose.orientation_rad = WallFollower::update_orientation(prev_pos, next_pos);
+                robot_state.pose.orientation_rad = WallFollower::update_orientation_allowing_diagonal(prev_pos, next_pos);
                 robot_state.pose.position = next_pos;
                 println!("Robot {} found alternative escape direction to ({}, {})", 
                          robot_state.id, next_pos.x, next_pos.y);
@@ -6,10 +6,12 @@ pub mod boundary_analysis;
 pub mod island_escape;
 pub mod interior_sweep;
 pub mod central_scan;
+pub mod frontier_exploration;
 
 pub use wall_find::WallFindPhase;
 pub use boundary_scouting::BoundaryScoutingPhase;
 pub use boundary_analysis::BoundaryAnalysisPhase;
 pub use island_escape::IslandEscapePhase;
 pub use interior_sweep::InteriorSweepPhase;
-pub use central_scan::CentralScanPhase;
\ No newline at end of file
+pub use central_scan::CentralScanPhase;
+pub use frontier_exploration::FrontierExplorationPhase;
\ No newline at end of file
@@ -1,33 +1,52 @@
 /// Initial wall finding phase implementation
 
 use crate::types::*;
+use crate::constants::*;
 use crate::robot_node::phase_trait::*;
+use crate::robot_node::sensing;
 
-/// Phase 1: Move in a straight line until a wall is seen directly in front
+/// Phase 1: Move in a straight line until a wall is seen directly in front.
+/// Biased by `momentum_prob` to repeat the previous step direction rather than
+/// re-sensing straight ahead every tick, which damps zig-zag jitter on open maps.
 #[derive(Debug, Clone)]
 pub struct WallFindPhase;
 
 impl RobotPhaseBehavior for WallFindPhase {
-    fn execute(&mut self, robot_state: &mut RobotState, _context: &PhaseContext) -> PhaseTransition {
-        let (next_pos, cell) = Self::sense_front(robot_state);
-        
-        match cell {
-            Some(CellState::Obstacle) => {
-                println!("Robot {} sees obstacle in front at ({}, {}), stopping and starting boundary scouting.", 
+    fn execute(&mut self, robot_state: &mut RobotState, context: &PhaseContext) -> PhaseTransition {
+        let direction = Self::choose_direction(robot_state);
+        robot_state.pose.orientation_rad = direction.to_rad();
+
+        // Cast a ray along the chosen heading out to SENSOR_RANGE, marking
+        // every cell it passes through on the local map -- populates the map
+        // for later frontier detection instead of discovering it one step
+        // at a time, and tells us in the same tick whether a wall lies
+        // within range ahead.
+        let ray = sensing::cast_ray(robot_state.pose.position, robot_state.pose.orientation_rad, SENSOR_RANGE, context.global_map);
+        for (pos, cell) in &ray {
+            if let Some(idx) = robot_state.map.coord_to_index(*pos) {
+                robot_state.map.cells[idx] = *cell;
+            }
+        }
+
+        let (dx, dy) = direction.to_vector();
+        let next_pos = Point {
+            x: robot_state.pose.position.x + dx,
+            y: robot_state.pose.position.y + dy,
+        };
+
+        match Self::cell_at(robot_state, next_pos) {
+            Some(CellState::Obstacle) | None => {
+                println!("Robot {} sees obstacle in front at ({}, {}), stopping and starting boundary scouting.",
                          robot_state.id, next_pos.x, next_pos.y);
                 PhaseTransition::Transition(RobotPhase::BoundaryScouting)
             }
             _ => {
-                // Move forward (-Y direction)
-                println!("Robot {} moves from ({}, {}) to ({}, {})", 
-                         robot_state.id, robot_state.pose.position.x, robot_state.pose.position.y, 
+                println!("Robot {} moves from ({}, {}) to ({}, {})",
+                         robot_state.id, robot_state.pose.position.x, robot_state.pose.position.y,
                          next_pos.x, next_pos.y);
                 robot_state.pose.position = next_pos;
-                
-                // Update local map (mark as empty)
-                let idx = (next_pos.y as usize) * robot_state.map.width + (next_pos.x as usize);
-                robot_state.map.cells[idx] = CellState::Empty;
-                
+                robot_state.last_wall_find_direction = Some(Point { x: dx, y: dy });
+
                 PhaseTransition::Continue
             }
         }
@@ -39,21 +58,46 @@ impl RobotPhaseBehavior for WallFindPhase {
 }
 
 impl WallFindPhase {
-    /// Sense the cell directly in front of the robot
-    fn sense_front(robot_state: &RobotState) -> (Point, Option<CellState>) {
-        let next_pos = Point {
-            x: robot_state.pose.position.x,
-            y: robot_state.pose.position.y - 1, // Move north (-Y direction)
-        };
-        
+    /// Choose this tick's heading: with probability `momentum_prob`, repeat
+    /// `last_wall_find_direction`; otherwise fall back to the robot's current
+    /// `pose.orientation_rad`, rounded to the nearest cardinal direction,
+    /// rather than a hard-coded heading.
+    fn choose_direction(robot_state: &RobotState) -> Direction {
+        let last_direction = robot_state.last_wall_find_direction
+            .and_then(|d| Direction::from_vector(d.x, d.y));
+
+        match last_direction {
+            Some(direction) if Self::momentum_roll(robot_state) < robot_state.momentum_prob => direction,
+            _ => Direction::from_rad(robot_state.pose.orientation_rad),
+        }
+    }
+
+    /// Deterministic pseudo-random roll in `[0, 1)`, seeded from robot id and
+    /// position so repeated ticks vary without threading an RNG through `RobotState`.
+    fn momentum_roll(robot_state: &RobotState) -> f64 {
+        let pos = robot_state.pose.position;
+        let mut seed = (robot_state.id as u64)
+            .wrapping_mul(0x9E3779B97F4A7C15)
+            .wrapping_add(pos.x as u64)
+            .wrapping_mul(0xBF58476D1CE4E5B9)
+            .wrapping_add(pos.y as u64);
+        seed ^= seed >> 33;
+        seed = seed.wrapping_mul(0xFF51AFD7ED558CCD);
+        seed ^= seed >> 33;
+        (seed % 1_000_000) as f64 / 1_000_000.0
+    }
+
+    /// Sense the cell at `pos` on the robot's own (just ray-updated) map,
+    /// treating out-of-bounds as an obstacle.
+    fn cell_at(robot_state: &RobotState, pos: Point) -> Option<CellState> {
         let width = robot_state.map.width as i32;
         let height = robot_state.map.height as i32;
-        
-        if next_pos.x < 0 || next_pos.x >= width || next_pos.y < 0 || next_pos.y >= height {
-            (next_pos, Some(CellState::Obstacle))
+
+        if pos.x < 0 || pos.x >= width || pos.y < 0 || pos.y >= height {
+            Some(CellState::Obstacle)
         } else {
-            let idx = (next_pos.y as usize) * robot_state.map.width + (next_pos.x as usize);
-            (next_pos, Some(robot_state.map.cells[idx]))
+            let idx = (pos.y as usize) * robot_state.map.width + (pos.x as usize);
+            Some(robot_state.map.cells[idx])
         }
     }
-}
\ No newline at end of file
+}
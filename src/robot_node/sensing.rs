@@ -0,0 +1,200 @@
+/// Line-of-sight sensing via recursive shadowcasting.
+///
+/// Restricts a robot to a sensor radius around its current pose and only reveals
+/// cells it can actually see, rather than letting scouting/return/rendezvous logic
+/// read the global map directly and have perfect knowledge.
+
+use crate::types::*;
+use crate::constants::*;
+use std::collections::HashMap;
+
+/// Computes every cell visible from `pose` on `map`, out to `SENSOR_RADIUS`.
+/// Obstacles block the cells directly behind them from the robot's point of view.
+pub fn compute_visible_cells(pose: &Pose, map: &GridMap) -> Vec<(Point, CellState)> {
+    let origin = pose.position;
+    let mut visible: HashMap<Point, CellState> = HashMap::new();
+
+    if let Some(&cell) = map.get(origin) {
+        visible.insert(origin, cell);
+    }
+
+    for octant in 0..8 {
+        cast_light(map, origin, 1, 1.0, 0.0, octant, &mut visible);
+    }
+
+    visible.into_iter().collect()
+}
+
+/// Recursively scans one octant of the field of view, row by row outward from
+/// the origin, narrowing the visible wedge (`start_slope`..=`end_slope`) whenever
+/// an obstacle splits it.
+fn cast_light(
+    map: &GridMap,
+    origin: Point,
+    row: i32,
+    start_slope: f64,
+    end_slope: f64,
+    octant: u8,
+    visible: &mut HashMap<Point, CellState>,
+) {
+    if start_slope < end_slope {
+        return;
+    }
+
+    let mut start_slope = start_slope;
+
+    for current_row in row..=SENSOR_RADIUS {
+        let mut blocked = false;
+        let mut next_start_slope = start_slope;
+
+        for col in (-current_row)..=0 {
+            // `octant_transform` folds each octant's sign flips into (dx, dy)
+            // (e.g. octant 0 maps row to dy = -row), so the slope here has to
+            // be taken against that same negated depth, not the raw positive
+            // `current_row` -- otherwise every slope comes out on the wrong
+            // side of zero and the very first column of the very first row
+            // already falls outside the full `(1.0, 0.0)` wedge, so nothing
+            // past the origin is ever revealed.
+            let depth = -(current_row as f64);
+            let left_slope = (col as f64 - 0.5) / depth;
+            let right_slope = (col as f64 + 0.5) / depth;
+
+            if right_slope > start_slope {
+                continue;
+            }
+            if left_slope < end_slope {
+                break;
+            }
+
+            let (dx, dy) = octant_transform(current_row, col, octant);
+            let cell_pos = Point { x: origin.x + dx, y: origin.y + dy };
+
+            if within_sensor_radius(dx, dy) {
+                if let Some(&cell) = map.get(cell_pos) {
+                    visible.insert(cell_pos, cell);
+                }
+            }
+
+            let is_obstacle = map.get(cell_pos) == Some(&CellState::Obstacle);
+
+            if blocked {
+                if is_obstacle {
+                    next_start_slope = right_slope;
+                    continue;
+                } else {
+                    blocked = false;
+                    start_slope = next_start_slope;
+                }
+            } else if is_obstacle && current_row < SENSOR_RADIUS {
+                // Obstacle splits the wedge: recurse into the sub-wedge before it
+                // with a tightened start_slope, then keep scanning this row with a
+                // tightened end_slope (cells beyond the obstacle stay Unexplored).
+                blocked = true;
+                cast_light(map, origin, current_row + 1, start_slope, left_slope, octant, visible);
+                next_start_slope = right_slope;
+            }
+        }
+
+        if blocked {
+            break;
+        }
+    }
+}
+
+/// Returns true if `(dx, dy)` (relative to the origin) falls within `SENSOR_RADIUS`,
+/// using the Euclidean metric.
+fn within_sensor_radius(dx: i32, dy: i32) -> bool {
+    (dx * dx + dy * dy) as f64 <= (SENSOR_RADIUS * SENSOR_RADIUS) as f64
+}
+
+/// Casts a forward-facing fan of `RAY_FAN_COUNT` rays spanning `RAY_FAN_WIDTH_RAD`,
+/// centered on `pose`'s `orientation_rad`, out to `RAY_SENSOR_RANGE` cells, using
+/// Amanatides-Woo grid DDA traversal. Complements `compute_visible_cells`'s short
+/// omnidirectional shadowcast with a longer, direction-biased look-ahead so
+/// boundary scouting and interior sweeping can build map knowledge from a
+/// distance instead of only discovering obstacles by bumping into them.
+pub fn cast_ray_fan(pose: &Pose, map: &GridMap) -> Vec<(Point, CellState)> {
+    let mut visible: HashMap<Point, CellState> = HashMap::new();
+    let half_width = RAY_FAN_WIDTH_RAD / 2.0;
+
+    for i in 0..RAY_FAN_COUNT {
+        let t = if RAY_FAN_COUNT == 1 { 0.5 } else { i as f64 / (RAY_FAN_COUNT - 1) as f64 };
+        let angle = pose.orientation_rad - half_width + t * RAY_FAN_WIDTH_RAD;
+        cast_ray_dda(pose.position, angle, RAY_SENSOR_RANGE, map, &mut visible);
+    }
+
+    visible.into_iter().collect()
+}
+
+/// Casts a single ray from `origin` at `direction_rad` out to `max_range`
+/// cells via Amanatides-Woo grid DDA, marking each traversed cell with
+/// `map`'s ground truth and stopping at (and including) the first
+/// `CellState::Obstacle`. The single-ray building block behind
+/// `cast_ray_fan`'s multi-ray sweep, exposed directly for callers that only
+/// need to check one heading -- e.g. `WallFindPhase`'s straight-line search.
+pub fn cast_ray(origin: Point, direction_rad: f64, max_range: i32, map: &GridMap) -> Vec<(Point, CellState)> {
+    let mut visible: HashMap<Point, CellState> = HashMap::new();
+    cast_ray_dda(origin, direction_rad, max_range, map, &mut visible);
+    visible.into_iter().collect()
+}
+
+/// Traces a single ray from `origin` at `angle_rad` using Amanatides-Woo grid
+/// traversal, inserting every cell it passes through into `visible` as
+/// whatever ground truth `map` holds there, up to and including the first
+/// `CellState::Obstacle` it strikes (then stopping that ray).
+fn cast_ray_dda(origin: Point, angle_rad: f64, max_range: i32, map: &GridMap, visible: &mut HashMap<Point, CellState>) {
+    let dx = angle_rad.cos();
+    let dy = angle_rad.sin();
+
+    let step_x: i32 = if dx > 0.0 { 1 } else if dx < 0.0 { -1 } else { 0 };
+    let step_y: i32 = if dy > 0.0 { 1 } else if dy < 0.0 { -1 } else { 0 };
+
+    let t_delta_x = if dx == 0.0 { f64::INFINITY } else { (1.0 / dx).abs() };
+    let t_delta_y = if dy == 0.0 { f64::INFINITY } else { (1.0 / dy).abs() };
+
+    // The origin sits at the center of its cell, so the nearest cell boundary
+    // in either direction starts out half a cell away.
+    let mut t_max_x = if dx == 0.0 { f64::INFINITY } else { 0.5 / dx.abs() };
+    let mut t_max_y = if dy == 0.0 { f64::INFINITY } else { 0.5 / dy.abs() };
+
+    let mut cell_x = origin.x;
+    let mut cell_y = origin.y;
+
+    for _ in 0..max_range {
+        if t_max_x < t_max_y {
+            cell_x += step_x;
+            t_max_x += t_delta_x;
+        } else {
+            cell_y += step_y;
+            t_max_y += t_delta_y;
+        }
+
+        let cell_pos = Point { x: cell_x, y: cell_y };
+        let cell = match map.get(cell_pos) {
+            Some(&cell) => cell,
+            None => break,
+        };
+
+        visible.insert(cell_pos, cell);
+
+        if cell == CellState::Obstacle {
+            break;
+        }
+    }
+}
+
+/// Maps octant-local (row = depth along the octant, col = transverse offset)
+/// coordinates to map-relative (dx, dy) offsets for each of the 8 octants.
+fn octant_transform(row: i32, col: i32, octant: u8) -> (i32, i32) {
+    match octant {
+        0 => (col, -row),
+        1 => (row, -col),
+        2 => (row, col),
+        3 => (col, row),
+        4 => (-col, row),
+        5 => (-row, col),
+        6 => (-row, -col),
+        7 => (-col, -row),
+        _ => unreachable!("only 8 octants"),
+    }
+}
@@ -0,0 +1,31 @@
+/// Declared legal edges of the autonomous phase state machine.
+///
+/// `RobotNode::tick_autonomous` used to assign `self.state.phase = new_phase`
+/// directly off whatever `PhaseTransition::Transition` a phase returned,
+/// which could not tell a legitimate hand-off from a bug that skipped a step.
+/// `validate` is the one place that decides whether an attempted `(from, to)`
+/// jump is real.
+
+use crate::types::{RobotPhase, Transition};
+
+/// Every transition a `RobotPhaseBehavior::execute` is allowed to request.
+/// Checked by `validate` before `tick_autonomous` commits a phase change.
+pub const TRANSITIONS: &[Transition] = &[
+    Transition { id: 0, label: "wall found, start tracing the boundary", from: RobotPhase::InitialWallFind, to: RobotPhase::BoundaryScouting },
+    Transition { id: 1, label: "boundary loop closed, analyze it", from: RobotPhase::BoundaryScouting, to: RobotPhase::BoundaryAnalysis },
+    Transition { id: 2, label: "loop is an island, escape it", from: RobotPhase::BoundaryAnalysis, to: RobotPhase::IslandEscape },
+    Transition { id: 3, label: "loop is the exterior wall, scan the interior", from: RobotPhase::BoundaryAnalysis, to: RobotPhase::CentralScan },
+    Transition { id: 4, label: "escaped the island, resume boundary scouting", from: RobotPhase::IslandEscape, to: RobotPhase::BoundaryScouting },
+    Transition { id: 5, label: "escaped the island, re-orient and find a new wall", from: RobotPhase::IslandEscape, to: RobotPhase::InitialWallFind },
+    Transition { id: 6, label: "central scan complete", from: RobotPhase::CentralScan, to: RobotPhase::Idle },
+    Transition { id: 7, label: "interior sweep complete", from: RobotPhase::InteriorSweep, to: RobotPhase::Idle },
+    Transition { id: 8, label: "frontier exploration complete", from: RobotPhase::FrontierExploration, to: RobotPhase::Idle },
+];
+
+/// Looks up the declared transition from `from` to `to`, if any. `None`
+/// means the jump is not in `TRANSITIONS` -- e.g. `InitialWallFind` straight
+/// to `InteriorSweep` -- so the caller can reject and log it rather than
+/// applying it silently.
+pub fn validate(from: RobotPhase, to: RobotPhase) -> Option<Transition> {
+    TRANSITIONS.iter().copied().find(|t| t.from == from && t.to == to)
+}
@@ -2,7 +2,7 @@
 
 use crate::types::*;
 use crate::constants::*;
-use std::f64::consts::PI;
+use crate::geometry;
 
 /// Wall-following utilities for robot navigation
 pub struct WallFollower;
@@ -15,43 +15,30 @@ impl WallFollower {
         global_map: &GridMap,
         partner_pos: Point,
     ) -> Option<Point> {
-        let (current_dx, current_dy) = Self::get_direction_vector(orientation);
+        let current_dir = Direction::from_rad(orientation);
+        let (current_dx, current_dy) = current_dir.to_vector();
 
         // Calculate direction away from partner
         let to_partner_x = partner_pos.x - current_pos.x;
         let to_partner_y = partner_pos.y - current_pos.y;
-        
+
         // Determine which side partner is on relative to current orientation
         // If partner is to the right, turn left; if partner is to the left, turn right
         let cross_product = current_dx * to_partner_y - current_dy * to_partner_x;
         let turn_left = cross_product > 0; // Partner is to the right, so turn left
-        
+
         let priorities = if turn_left {
             // Turn left (away from partner on right)
-            vec![
-                (current_dy, -current_dx),  // Relative Left (CCW rotation: 90° left from current)
-                (current_dx, current_dy),   // Forward
-                (-current_dy, current_dx),  // Relative Right (CW rotation: 90° right from current)
-                (-current_dx, -current_dy), // Back
-            ]
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
         } else {
             // Turn right (away from partner on left)
-            vec![
-                (-current_dy, current_dx),  // Relative Right (CW rotation: 90° right from current)
-                (current_dx, current_dy),   // Forward
-                (current_dy, -current_dx),  // Relative Left (CCW rotation: 90° left from current)
-                (-current_dx, -current_dy), // Back
-            ]
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
         };
 
         // Try each direction in priority order
-        for (dx, dy) in priorities {
-            let next_pos = Point {
-                x: current_pos.x + dx,
-                y: current_pos.y + dy,
-            };
-
-            if Self::is_position_valid_and_empty(next_pos, global_map) {
+        for dir in priorities {
+            let (dx, dy) = dir.to_vector();
+            if let Some(next_pos) = Self::resolve_step(current_pos, dx, dy, global_map) {
                 return Some(next_pos);
             }
         }
@@ -67,36 +54,20 @@ impl WallFollower {
         global_map: &GridMap,
         tracing_direction: i8,
     ) -> Option<Point> {
-        // Get current direction vector
-        let (current_dx, current_dy) = Self::get_direction_vector(orientation);
-
         // Priority order depends on tracing direction - proper wall following
+        let current_dir = Direction::from_rad(orientation);
         let priorities = if tracing_direction == LEFT_HAND_RULE {
             // Left-hand rule: keep wall on left, so try right first
-            vec![
-                (-current_dy, current_dx),  // Relative Right (CW rotation: 90° right from current)
-                (current_dx, current_dy),   // Forward
-                (current_dy, -current_dx),  // Relative Left (CCW rotation: 90° left from current)
-                (-current_dx, -current_dy), // Back
-            ]
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
         } else {
             // Right-hand rule: keep wall on right, so try left first
-            vec![
-                (current_dy, -current_dx),  // Relative Left (CCW rotation: 90° left from current)
-                (current_dx, current_dy),   // Forward
-                (-current_dy, current_dx),  // Relative Right (CW rotation: 90° right from current)
-                (-current_dx, -current_dy), // Back
-            ]
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
         };
 
         // Try each direction in priority order
-        for (dx, dy) in priorities {
-            let next_pos = Point {
-                x: current_pos.x + dx,
-                y: current_pos.y + dy,
-            };
-
-            if Self::is_position_valid_and_empty(next_pos, global_map) {
+        for dir in priorities {
+            let (dx, dy) = dir.to_vector();
+            if let Some(next_pos) = Self::resolve_step(current_pos, dx, dy, global_map) {
                 return Some(next_pos);
             }
         }
@@ -104,31 +75,47 @@ impl WallFollower {
         None
     }
 
+    /// Like `wall_follow_step`, but chooses among all valid candidate moves
+    /// the one whose `GridMap::distance_transform` clearance is closest to
+    /// `desired_clearance`, rather than always taking the first in priority
+    /// order. Ties are broken by that same left/right-hand priority order,
+    /// so a robot aiming for a clearance of, say, 2 still prefers turning
+    /// the wall-following way when two candidates tie on clearance. Produces
+    /// smoother, more collision-safe traces than always hugging the wall at
+    /// distance 1.
+    pub fn wall_follow_step_with_clearance(
+        current_pos: Point,
+        orientation: f64,
+        global_map: &GridMap,
+        tracing_direction: i8,
+        desired_clearance: u32,
+    ) -> Option<Point> {
+        let current_dir = Direction::from_rad(orientation);
+        let priorities = if tracing_direction == LEFT_HAND_RULE {
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
+        } else {
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
+        };
+
+        let clearance = global_map.distance_transform();
+
+        priorities.iter()
+            .enumerate()
+            .filter_map(|(priority, dir)| {
+                let (dx, dy) = dir.to_vector();
+                Self::resolve_step(current_pos, dx, dy, global_map).map(|next_pos| (priority, next_pos))
+            })
+            .min_by_key(|&(priority, next_pos)| {
+                let idx = (next_pos.y as usize) * global_map.width + (next_pos.x as usize);
+                let diff = (clearance[idx] as i64 - desired_clearance as i64).abs();
+                (diff, priority)
+            })
+            .map(|(_, next_pos)| next_pos)
+    }
+
     /// Translates orientation (radians) into a (dx, dy) movement vector
     pub fn get_direction_vector(orientation_rad: f64) -> (i32, i32) {
-        // Normalize angle to be between -PI and PI
-        let angle = orientation_rad.rem_euclid(2.0 * PI);
-        let angle_deg = angle.to_degrees().round() as i32;
-
-        // Using rounded degrees to avoid floating point comparison issues
-        // North (-Y): -90 or 270
-        // South (+Y): 90
-        // East (+X): 0
-        // West (-X): 180 or -180
-
-        if angle_deg == 0 { // East
-            EAST
-        } else if angle_deg == 90 { // South
-            SOUTH
-        } else if angle_deg == 180 || angle_deg == -180 { // West
-            WEST
-        } else if angle_deg == 270 || angle_deg == -90 { // North
-            NORTH
-        } else {
-            // Default to North if orientation is not one of the cardinal directions
-            println!("Warning: Non-cardinal orientation: {}. Defaulting to North.", orientation_rad.to_degrees());
-            NORTH
-        }
+        Direction::from_rad(orientation_rad).to_vector()
     }
 
     /// Check if a position is valid and empty
@@ -142,6 +129,174 @@ impl WallFollower {
         global_map.cells[idx] != CellState::Obstacle
     }
 
+    /// Resolves one candidate step of `(dx, dy)` from `current_pos` against the
+    /// map's `Topology`, returning the landing position if it's passable. A step
+    /// that stays in-bounds is checked directly; one that leaves the map falls
+    /// back to `GridMap::wrap_position` (`Toroidal`) or `GridMap::resolve_portal`
+    /// (`Portals`) before finally being treated as blocked, so the map edge is
+    /// only an obstacle when no wrap/portal applies.
+    fn resolve_step(current_pos: Point, dx: i32, dy: i32, global_map: &GridMap) -> Option<Point> {
+        let raw = Point { x: current_pos.x + dx, y: current_pos.y + dy };
+
+        let landing = global_map
+            .wrap_position(raw)
+            .or_else(|| global_map.resolve_portal(current_pos, Point { x: dx, y: dy }).map(|(entry, _)| entry))?;
+
+        if Self::is_position_valid_and_empty(landing, global_map) {
+            Some(landing)
+        } else {
+            None
+        }
+    }
+
+    /// Wall-following step that additionally rejects any candidate position that
+    /// would place this robot within collision radius (`geometry::ROBOT_RADIUS`)
+    /// of another robot, forcing the caller to re-plan next tick instead of
+    /// stepping onto/through another robot.
+    pub fn wall_follow_step_avoiding(
+        current_pos: Point,
+        orientation: f64,
+        global_map: &GridMap,
+        tracing_direction: i8,
+        other_positions: &[Point],
+    ) -> Option<Point> {
+        let current_dir = Direction::from_rad(orientation);
+        let priorities = if tracing_direction == LEFT_HAND_RULE {
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
+        } else {
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
+        };
+
+        for dir in priorities {
+            let (dx, dy) = dir.to_vector();
+            if let Some(next_pos) = Self::resolve_step(current_pos, dx, dy, global_map) {
+                if !other_positions.iter().any(|&other| geometry::robots_would_collide(next_pos, other)) {
+                    return Some(next_pos);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Like `wall_follow_step_avoiding`, but among whichever collision-free
+    /// candidates remain, breaks ties toward the move whose `(dx, dy)` has
+    /// the largest dot product with `repulsion_vector`'s accumulated
+    /// boids-style separation bias from `other_positions` -- nudging the
+    /// robot away from nearby teammates instead of always taking the first
+    /// move in left/right-hand priority order. Only ever reorders among
+    /// already-valid, already-collision-free wall-following moves, so it
+    /// can't break the tracing invariant or walk into an obstacle. Keeps
+    /// both robots spread out along the boundary instead of clustering and
+    /// re-tracing the same segment.
+    ///
+    /// A second tie-break, below the repulsion score, prefers the candidate
+    /// with the lower value in `pheromone` (a flattened `pheromone_width`-wide
+    /// grid parallel to `global_map.cells`) -- steering away from ground this
+    /// or the partner robot has recently trodden, on top of the instantaneous
+    /// teammate-separation bias above.
+    ///
+    /// Also takes a `momentum_prob` bias: the strict hand-rule priority order
+    /// always tries turning into the wall-hugging direction before continuing
+    /// straight, which at a concave corner can flip the robot's heading every
+    /// tick (e.g. facing East then West on consecutive steps) since both
+    /// turns are equally "correct" by the rule. With probability
+    /// `momentum_prob`, and only when the straight-ahead cell is passable,
+    /// this promotes continuing straight to the top priority instead,
+    /// damping that oscillation -- mirroring `WallFindPhase`'s momentum bias
+    /// for `InitialWallFind`, but applied to the wall-following priority
+    /// order rather than a single straight-line heading.
+    pub fn wall_follow_step_with_repulsion(
+        current_pos: Point,
+        orientation: f64,
+        global_map: &GridMap,
+        tracing_direction: i8,
+        other_positions: &[Point],
+        pheromone: &[f32],
+        pheromone_width: usize,
+        momentum_prob: f64,
+    ) -> Option<Point> {
+        let current_dir = Direction::from_rad(orientation);
+        let mut priorities = if tracing_direction == LEFT_HAND_RULE {
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
+        } else {
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
+        };
+
+        let (straight_dx, straight_dy) = current_dir.to_vector();
+        let straight_ahead_passable = Self::resolve_step(current_pos, straight_dx, straight_dy, global_map).is_some();
+        if straight_ahead_passable && Self::momentum_roll(current_pos) < momentum_prob {
+            priorities.swap(0, 1);
+        }
+
+        let (rx, ry) = Self::repulsion_vector(current_pos, other_positions);
+
+        let mut best: Option<(usize, f64, f32, Point)> = None;
+        for (priority, dir) in priorities.iter().enumerate() {
+            let (dx, dy) = dir.to_vector();
+            let Some(next_pos) = Self::resolve_step(current_pos, dx, dy, global_map) else { continue };
+            if other_positions.iter().any(|&other| geometry::robots_would_collide(next_pos, other)) {
+                continue;
+            }
+
+            let score = dx as f64 * rx + dy as f64 * ry;
+            let trail = pheromone.get(next_pos.y as usize * pheromone_width + next_pos.x as usize).copied().unwrap_or(0.0);
+            let replace = match best {
+                None => true,
+                Some((best_priority, best_score, best_trail, _)) => {
+                    score > best_score
+                        || (score == best_score && trail < best_trail)
+                        || (score == best_score && trail == best_trail && priority < best_priority)
+                }
+            };
+            if replace {
+                best = Some((priority, score, trail, next_pos));
+            }
+        }
+
+        best.map(|(_, _, _, next_pos)| next_pos)
+    }
+
+    /// Accumulates a boids-style separation vector: for each position in
+    /// `other_positions` within `COMMUNICATION_RANGE` (Manhattan distance) of
+    /// `current_pos`, adds `(current_pos - other) / dist^2`. A teammate
+    /// outside communication range, or exactly on top of `current_pos`,
+    /// contributes nothing -- a robot can't react to a teammate it hasn't
+    /// heard from, and a zero distance has no defined direction to flee.
+    fn repulsion_vector(current_pos: Point, other_positions: &[Point]) -> (f64, f64) {
+        let mut sum = (0.0, 0.0);
+        for &other in other_positions {
+            let manhattan = (current_pos.x - other.x).abs() + (current_pos.y - other.y).abs();
+            if manhattan == 0 || manhattan > COMMUNICATION_RANGE {
+                continue;
+            }
+            let dx = (current_pos.x - other.x) as f64;
+            let dy = (current_pos.y - other.y) as f64;
+            let dist_sq = dx * dx + dy * dy;
+            sum.0 += dx / dist_sq;
+            sum.1 += dy / dist_sq;
+        }
+        sum
+    }
+
+    /// Deterministic pseudo-random roll in `[0, 1)` for the momentum bias in
+    /// `wall_follow_step_with_repulsion`, seeded from position alone --
+    /// mirrors `WallFindPhase::momentum_roll`'s approach of avoiding a
+    /// dedicated RNG field on `RobotState` by hashing state that's already
+    /// available, just without the robot id term (a single robot's own
+    /// wall-following trace never revisits the same cell on the same leg, so
+    /// there's no need to also vary by id here).
+    fn momentum_roll(pos: Point) -> f64 {
+        let mut seed = (pos.x as u64)
+            .wrapping_mul(0x9E3779B97F4A7C15)
+            .wrapping_add(pos.y as u64)
+            .wrapping_mul(0xBF58476D1CE4E5B9);
+        seed ^= seed >> 33;
+        seed = seed.wrapping_mul(0xFF51AFD7ED558CCD);
+        seed ^= seed >> 33;
+        (seed % 1_000_000) as f64 / 1_000_000.0
+    }
+
     /// Check if a position is part of any virtual boundary (completed loop)
     pub fn is_virtual_wall(pos: Point, virtual_boundaries: &[Vec<Point>]) -> bool {
         for boundary in virtual_boundaries {
@@ -162,7 +317,12 @@ impl WallFollower {
         !Self::is_virtual_wall(pos, virtual_boundaries)
     }
 
-    /// Wall-following step with virtual boundary support
+    /// Wall-following step with virtual boundary support. Candidate steps
+    /// are resolved through `resolve_step`, so a step off the map edge wraps
+    /// (`Toroidal`) or teleports through a portal (`Portals`) the same as
+    /// every other `wall_follow_step*` variant, instead of treating the map
+    /// edge as an implicit wall; a virtual boundary is then checked against
+    /// the resolved landing cell.
     pub fn wall_follow_step_virtual(
         current_pos: Point,
         orientation: f64,
@@ -170,33 +330,18 @@ impl WallFollower {
         virtual_boundaries: &[Vec<Point>],
         tracing_direction: i8,
     ) -> Option<Point> {
-        let (current_dx, current_dy) = Self::get_direction_vector(orientation);
-
+        let current_dir = Direction::from_rad(orientation);
         let priorities = if tracing_direction == LEFT_HAND_RULE {
-            // Left-hand rule: try left, forward, right, back
-            [
-                (-current_dy, current_dx),   // Left (90° counterclockwise)
-                (current_dx, current_dy),    // Forward
-                (current_dy, -current_dx),   // Right (90° clockwise)
-                (-current_dx, -current_dy),  // Backward
-            ]
+            [current_dir.turn_right(), current_dir, current_dir.turn_left(), current_dir.reverse()]
         } else {
-            // Right-hand rule: try right, forward, left, back
-            [
-                (current_dy, -current_dx),   // Right (90° clockwise)
-                (current_dx, current_dy),    // Forward
-                (-current_dy, current_dx),   // Left (90° counterclockwise)
-                (-current_dx, -current_dy),  // Backward
-            ]
+            [current_dir.turn_left(), current_dir, current_dir.turn_right(), current_dir.reverse()]
         };
 
-        for (dx, dy) in &priorities {
-            let next_pos = Point {
-                x: current_pos.x + dx,
-                y: current_pos.y + dy,
-            };
+        for dir in priorities {
+            let (dx, dy) = dir.to_vector();
+            let Some(next_pos) = Self::resolve_step(current_pos, dx, dy, global_map) else { continue };
 
-            if Self::is_position_valid_and_empty_virtual(next_pos, global_map, virtual_boundaries) {
+            if !Self::is_virtual_wall(next_pos, virtual_boundaries) {
                 return Some(next_pos);
             }
         }
@@ -208,18 +353,68 @@ impl WallFollower {
     pub fn update_orientation(prev_pos: Point, next_pos: Point) -> f64 {
         let dx = next_pos.x - prev_pos.x;
         let dy = next_pos.y - prev_pos.y;
-        
-        match (dx, dy) {
-            (1, 0) => EAST_RAD,    // East
-            (-1, 0) => WEST_RAD,   // West
-            (0, 1) => SOUTH_RAD,   // South
-            (0, -1) => NORTH_RAD,  // North
-            _ => {
+
+        match Direction::from_vector(dx, dy) {
+            Some(dir) => dir.to_rad(),
+            None => {
                 println!("Warning: Invalid move vector ({}, {})", dx, dy);
                 NORTH_RAD // Default
             }
         }
     }
+
+    /// Like `update_orientation`, but also accepts diagonal move vectors (both
+    /// `dx` and `dy` nonzero), which `Direction` has no cardinal bucket for --
+    /// used by `Connectivity::Eight` movement instead of quantizing a diagonal
+    /// step into the "invalid move vector" default.
+    pub fn update_orientation_allowing_diagonal(prev_pos: Point, next_pos: Point) -> f64 {
+        let dx = next_pos.x - prev_pos.x;
+        let dy = next_pos.y - prev_pos.y;
+
+        if dx != 0 && dy != 0 {
+            (dy as f64).atan2(dx as f64)
+        } else {
+            Self::update_orientation(prev_pos, next_pos)
+        }
+    }
+
+    /// Like `update_orientation`, but normalizes the move vector against `map`'s
+    /// `Topology` first. A `Toroidal` step that wrapped around an edge looks like
+    /// a large jump in raw coordinates even though it's a single cell in the
+    /// grid graph, so each axis is folded back to the shorter of the direct and
+    /// wrapped-around delta before the usual cardinal-direction match. A
+    /// `Portals` step that crossed a seam is an even bigger jump that has no
+    /// meaningful raw delta at all, so it's handled separately: the heading
+    /// comes from the matching portal's `entry_direction` rather than from
+    /// `next_pos - prev_pos`.
+    pub fn update_orientation_wrapped(prev_pos: Point, next_pos: Point, map: &GridMap) -> f64 {
+        if let Topology::Portals(portals) = &map.topology {
+            let taken = portals.iter()
+                .find(|portal| portal.boundary_cell == prev_pos && portal.entry_cell == next_pos);
+            if let Some(portal) = taken {
+                let (dx, dy) = (portal.entry_direction.x, portal.entry_direction.y);
+                if let Some(dir) = Direction::from_vector(dx, dy) {
+                    return dir.to_rad();
+                }
+            }
+        }
+
+        let mut dx = next_pos.x - prev_pos.x;
+        let mut dy = next_pos.y - prev_pos.y;
+
+        if map.topology == Topology::Toroidal {
+            let width = map.width as i32;
+            let height = map.height as i32;
+            if dx.abs() > 1 {
+                dx = if dx > 0 { dx - width } else { dx + width };
+            }
+            if dy.abs() > 1 {
+                dy = if dy > 0 { dy - height } else { dy + height };
+            }
+        }
+
+        Self::update_orientation(prev_pos, Point { x: prev_pos.x + dx, y: prev_pos.y + dy })
+    }
 }
 
 /// Rotation tracking utilities
@@ -228,27 +423,11 @@ pub struct RotationTracker;
 impl RotationTracker {
     /// Calculate the rotation change in 90-degree steps
     pub fn calculate_rotation_steps(prev_orientation: f64, new_orientation: f64) -> i32 {
-        // Convert orientations to 90-degree steps
-        let prev_step = Self::orientation_to_step(prev_orientation);
-        let new_step = Self::orientation_to_step(new_orientation);
-        
-        // Calculate the shortest rotation (handling wrap-around)
-        let mut diff = new_step - prev_step;
-        if diff > 2 {
-            diff -= 4;
-        } else if diff < -2 {
-            diff += 4;
-        }
-        
-        diff
+        Direction::from_rad(prev_orientation).rotation_steps_to(Direction::from_rad(new_orientation))
     }
-    
+
     /// Convert orientation (radians) to 90-degree step (0=East, 1=South, 2=West, 3=North)
     pub fn orientation_to_step(orientation: f64) -> i32 {
-        // Normalize angle to [0, 2π)
-        let normalized = orientation.rem_euclid(2.0 * PI);
-        
-        // Convert to steps: 0=East(0°), 1=South(90°), 2=West(180°), 3=North(270°)
-        ((normalized + PI / 4.0) / (PI / 2.0)).floor() as i32 % 4
+        Direction::from_rad(orientation).step_index()
     }
 }
\ No newline at end of file
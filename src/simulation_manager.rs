@@ -1,15 +1,76 @@
 use crate::types::*;
+use crate::constants::*;
 use crate::robot_node::RobotNode;
 use crate::map_loader;
 use rand::seq::SliceRandom;
 use rand::{SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
+use std::io::{self, BufRead, Write};
+
+/// One tick's worth of recorded robot state, written as a single
+/// newline-delimited JSON line by `SimulationManager::tick` when `record` is
+/// set, and read back the same way by `replay_from`. Deliberately much
+/// smaller than a full `RobotState` (no per-robot map) -- paired with the
+/// `seed` that built the run's `SimulationManager`, a sequence of these is
+/// enough to turn an ASCII test map into a golden trace instead of a
+/// hand-written step-by-step assertion.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TickSnapshot {
+    pub tick: usize,
+    pub robots: Vec<RobotSnapshot>,
+}
+
+/// The portion of one robot's state worth keeping in a recorded trace.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RobotSnapshot {
+    pub id: u8,
+    pub pose: Pose,
+    pub phase: RobotPhase,
+    pub total_rotation_steps: Option<i32>,
+}
+
+impl RobotSnapshot {
+    fn from_robot(robot: &RobotNode) -> Self {
+        RobotSnapshot {
+            id: robot.state.id,
+            pose: robot.state.pose,
+            phase: robot.state.phase,
+            total_rotation_steps: robot.state.boundary_scout.as_ref().map(|s| s.total_rotation_steps),
+        }
+    }
+}
 
 /// Manages the simulation environment, robots, and clock.
 pub struct SimulationManager {
     // The environment grid, robots, and other state
     pub map: GridMap,
     pub robots: Vec<RobotNode>,
+    /// Policy applied to steps that would leave the map bounds. Defaults to
+    /// `Absorb`, matching the existing wall-following behavior of treating the
+    /// map edge as a wall.
+    pub boundary_condition: BoundaryCondition,
+    /// Shared stigmergic trail layer, parallel to `map.cells`: each robot
+    /// deposits `pheromone_deposit` onto its own cell every tick, and the
+    /// whole layer decays multiplicatively by `pheromone_decay` once per
+    /// `tick()`. Read by movement selection (see
+    /// `WallFollower::wall_follow_step_with_repulsion`) to steer robots away
+    /// from recently-trodden ground, reducing redundant re-coverage between
+    /// the two robots.
+    pub pheromone: Vec<f32>,
+    pub pheromone_deposit: f32,
+    pub pheromone_decay: f32,
+    /// When set, `tick()` appends one `TickSnapshot` line to this writer
+    /// every tick, in addition to advancing the simulation. Paired with
+    /// `from_map_file`'s `seed`, the resulting log plus the original map
+    /// makes a run reproducible well enough to diff against a golden trace
+    /// with `replay_from` instead of re-deriving expected positions by hand.
+    pub record: Option<Box<dyn Write>>,
+    tick_index: usize,
+    /// Set by `tick()` the first time any robot's occupied cell coincides
+    /// with a `CellState::Goal` cell: the reaching robot's id paired with
+    /// the tick count it took. `None` while the goal is still unreached.
+    pub goal_reached: Option<(u8, usize)>,
     // Add simulation clock or other fields as needed
 }
 
@@ -17,13 +78,27 @@ impl SimulationManager {
     /// Initializes the simulation with a map and two robots.
     pub fn new(map: GridMap, robot_states: Vec<RobotState>) -> Self {
         let robots = robot_states.into_iter().map(|state| RobotNode::new(state)).collect();
-        Self { map, robots }
+        let pheromone = vec![0.0; map.cells.len()];
+        Self {
+            map,
+            robots,
+            boundary_condition: BoundaryCondition::Absorb,
+            pheromone,
+            pheromone_deposit: PHEROMONE_DEPOSIT,
+            pheromone_decay: PHEROMONE_DECAY,
+            record: None,
+            tick_index: 0,
+            goal_reached: None,
+        }
     }
 
     /// Loads a map from a file and initializes two robots at random adjacent empty cells (left-to-right), both facing up (-Y direction).
     /// Accepts a random seed for reproducibility.
     pub fn from_map_file(path: &str, seed: u64) -> std::io::Result<Self> {
         let map = map_loader::load_map_from_file(path)?;
+        if !map.cells.iter().any(|&cell| cell == CellState::Goal) {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Map file has no goal ('O') cell"));
+        }
         let mut rng = StdRng::seed_from_u64(seed);
         let mut pairs = Vec::new();
         let w = map.width as i32;
@@ -44,33 +119,43 @@ impl SimulationManager {
                 id: 0,
                 pose: Pose { position: Point { x: x0, y: y0 }, orientation_rad: -PI/2.0 },
                 phase: RobotPhase::InitialWallFind,
-                map: GridMap {
-                    width: map.width,
-                    height: map.height,
-                    cells: vec![CellState::Unexplored; map.width * map.height],
-                },
+                map: Grid { width: map.width, height: map.height, cells: vec![CellState::Unexplored; map.width * map.height], topology: map.topology.clone() },
                 scout_depth_n: 3,
                 partner_id: 1,
                 last_known_partner_pose: None,
                 loop_analysis_data: None,
                 travel_direction_before_island: None,
                 boundary_scout: None,
+                central_scan: None,
+                frontier_exploration: None,
+                momentum_prob: DEFAULT_MOMENTUM_PROB,
+                last_wall_find_direction: None,
+                connectivity: Connectivity::Four,
+                preferred_wall_follow: None,
+                assigned_frontier_goal: None,
+                tick_count: 0,
+                phase_history: Vec::new(),
             },
             RobotState {
                 id: 1,
                 pose: Pose { position: Point { x: x1, y: y1 }, orientation_rad: -PI/2.0 },
                 phase: RobotPhase::InitialWallFind,
-                map: GridMap {
-                    width: map.width,
-                    height: map.height,
-                    cells: vec![CellState::Unexplored; map.width * map.height],
-                },
+                map: Grid { width: map.width, height: map.height, cells: vec![CellState::Unexplored; map.width * map.height], topology: map.topology.clone() },
                 scout_depth_n: 3,
                 partner_id: 0,
                 last_known_partner_pose: None,
                 loop_analysis_data: None,
                 travel_direction_before_island: None,
                 boundary_scout: None,
+                central_scan: None,
+                frontier_exploration: None,
+                momentum_prob: DEFAULT_MOMENTUM_PROB,
+                last_wall_find_direction: None,
+                connectivity: Connectivity::Four,
+                preferred_wall_follow: None,
+                assigned_frontier_goal: None,
+                tick_count: 0,
+                phase_history: Vec::new(),
             },
         ];
         // After setting positions, update each robot's local map with initial surroundings
@@ -82,14 +167,80 @@ impl SimulationManager {
         Ok(Self::new(map, robot_states))
     }
 
-    /// Advances the simulation by one tick.
-    pub fn tick(&mut self) {
+    /// Advances the simulation by one tick. Returns `true` once a robot has
+    /// reached a goal cell (see `goal_reached`), so a caller's run loop can
+    /// treat this the same way it already treats `RobotNode::tick`'s
+    /// per-robot completed flag.
+    pub fn tick(&mut self) -> bool {
         // Clone robots for read-only reference to pass to each tick
         let robots_snapshot = self.robots.clone();
         for robot in &mut self.robots {
-            robot.tick(&robots_snapshot, &self.map);
+            robot.tick(&robots_snapshot, &self.map, &mut self.pheromone, self.pheromone_deposit);
+        }
+        for cell in &mut self.pheromone {
+            *cell *= self.pheromone_decay;
+        }
+
+        if self.goal_reached.is_none() {
+            if let Some(robot) = self.robots.iter().find(|r| self.map.get(r.state.pose.position) == Some(&CellState::Goal)) {
+                let ticks = self.tick_index + 1;
+                self.goal_reached = Some((robot.state.id, ticks));
+                println!("Robot {} reached the goal after {} ticks!", robot.state.id, ticks);
+            }
+        }
+
+        if let Some(writer) = self.record.as_mut() {
+            let snapshot = TickSnapshot {
+                tick: self.tick_index,
+                robots: self.robots.iter().map(RobotSnapshot::from_robot).collect(),
+            };
+            // A run's recording is diagnostic, not load-bearing for the
+            // simulation itself, so a write failure (e.g. a full disk) is
+            // logged rather than aborting the run.
+            let wrote = serde_json::to_writer(&mut *writer, &snapshot)
+                .map_err(io::Error::from)
+                .and_then(|_| writeln!(writer));
+            if let Err(e) = wrote {
+                eprintln!("Failed to record tick {}: {}", self.tick_index, e);
+            }
+        }
+        self.tick_index += 1;
+
+        self.goal_reached.is_some()
+    }
+
+    /// Reconstructs a `SimulationManager` from `map`/`robot_states` (the same
+    /// inputs the original run was built from -- a recorded trace only holds
+    /// per-tick `TickSnapshot`s, not the map or full robot state) and reads
+    /// back every `TickSnapshot` line `tick()` wrote to `record`. Returns the
+    /// manager alongside the full snapshot sequence so a caller can step
+    /// through it deterministically with `apply_recorded_tick` instead of
+    /// re-running (and potentially diverging from) the autonomous phase logic.
+    pub fn replay_from(map: GridMap, robot_states: Vec<RobotState>, reader: impl BufRead) -> io::Result<(Self, Vec<TickSnapshot>)> {
+        let sim = Self::new(map, robot_states);
+        let mut snapshots = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let snapshot: TickSnapshot = serde_json::from_str(&line).map_err(io::Error::from)?;
+            snapshots.push(snapshot);
+        }
+        Ok((sim, snapshots))
+    }
+
+    /// Applies one recorded `TickSnapshot` directly to `self.robots`' pose and
+    /// phase, advancing `tick_index` to match -- replaying what was already
+    /// decided rather than re-deciding it.
+    pub fn apply_recorded_tick(&mut self, snapshot: &TickSnapshot) {
+        for robot_snapshot in &snapshot.robots {
+            if let Some(robot) = self.robots.iter_mut().find(|r| r.state.id == robot_snapshot.id) {
+                robot.state.pose = robot_snapshot.pose;
+                robot.state.phase = robot_snapshot.phase;
+            }
         }
-        // TODO: Add global termination checks, communication, etc.
+        self.tick_index = snapshot.tick + 1;
     }
 
     /// Print all robots' maps for inspection.
@@ -98,4 +249,4 @@ impl SimulationManager {
             robot.print_map();
         }
     }
-} 
\ No newline at end of file
+}
\ No newline at end of file
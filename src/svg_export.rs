@@ -0,0 +1,172 @@
+/// Headless SVG export of explored maps and robot trajectories.
+///
+/// The interactive `VisualizeApp` (in `src/visualize.rs`, gated behind `eframe`)
+/// is the only way to inspect a run today. This module renders any tick of a
+/// recorded `history` to a standalone SVG instead, so results can be embedded
+/// in papers or diffed in version control without launching a GUI.
+
+use crate::robot_node::RobotNode;
+use crate::types::{CellState, GridMap, Point};
+use std::io::{self, Write};
+
+/// Pixel size of one grid cell, matching `VisualizeApp`'s 20.0 so exported SVGs
+/// line up visually with the live viewer.
+const CELL_SIZE: f64 = 20.0;
+
+/// Per-robot trajectory styling, mirroring the mapping-toolkit line renderer:
+/// configurable stroke width/color/join/cap, with an optional dash pattern used
+/// for the "returning" leg of a boundary-scouting run (drawn over a solid
+/// gap-colored underlay so the dashes read clearly against the map).
+#[derive(Clone, Debug)]
+pub struct TrajectoryStyle {
+    pub stroke_color: String,
+    pub stroke_width: f64,
+    pub returning_dash: Option<String>,
+    pub returning_gap_color: String,
+}
+
+impl TrajectoryStyle {
+    /// Default styling for the `index`-th robot, using the same blue/orange
+    /// palette as `VisualizeApp`.
+    pub fn for_robot(index: usize) -> Self {
+        let stroke_color = if index == 0 { "#0078ff" } else { "#ff5000" }.to_string();
+        Self {
+            stroke_color,
+            stroke_width: 2.5,
+            returning_dash: Some("6,4".to_string()),
+            returning_gap_color: "#ffffff".to_string(),
+        }
+    }
+}
+
+/// Renders the map and each robot's accumulated trajectory (from tick 0 through
+/// `tick`) as a standalone SVG document.
+pub fn export_svg(history: &[Vec<RobotNode>], tick: usize, out: &mut impl Write) -> io::Result<()> {
+    let snapshot = &history[tick];
+    let map = &snapshot[0].state.map;
+    let width_px = map.width as f64 * CELL_SIZE;
+    let height_px = map.height as f64 * CELL_SIZE;
+
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.1}" height="{:.1}" viewBox="0 0 {:.1} {:.1}">"#,
+        width_px, height_px, width_px, height_px
+    )?;
+
+    write_map_cells(out, map)?;
+
+    for (index, _) in snapshot.iter().enumerate() {
+        let style = TrajectoryStyle::for_robot(index);
+        let positions = trajectory_up_to(history, index, tick);
+        write_trajectory(out, &positions, &style)?;
+    }
+
+    writeln!(out, "</svg>")
+}
+
+/// Writes one `<rect>` per `Empty`/`Obstacle`/`Goal` cell, matching
+/// `VisualizeApp`'s white-fill / black-fill-with-white-stroke / green-fill
+/// colors. `Unexplored` cells are left blank.
+fn write_map_cells(out: &mut impl Write, map: &GridMap) -> io::Result<()> {
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let idx = y * map.width + x;
+            let (x0, y0) = (x as f64 * CELL_SIZE, y as f64 * CELL_SIZE);
+            match map.cells[idx] {
+                CellState::Obstacle => writeln!(
+                    out,
+                    r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="#000000" stroke="#ffffff" stroke-width="1.5"/>"##,
+                    x0, y0, CELL_SIZE, CELL_SIZE
+                )?,
+                CellState::Empty => writeln!(
+                    out,
+                    r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="#ffffff"/>"##,
+                    x0, y0, CELL_SIZE, CELL_SIZE
+                )?,
+                CellState::Goal => writeln!(
+                    out,
+                    r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="#2ecc71"/>"##,
+                    x0, y0, CELL_SIZE, CELL_SIZE
+                )?,
+                CellState::Unexplored => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Collects `history[0..=tick]`'s positions for robot `index`, each tagged with
+/// whether the robot was on a return leg at that tick, so the trajectory can be
+/// split into solid (forward) and dashed (returning) runs.
+fn trajectory_up_to(history: &[Vec<RobotNode>], index: usize, tick: usize) -> Vec<(Point, bool)> {
+    history[..=tick]
+        .iter()
+        .filter_map(|snapshot| snapshot.get(index))
+        .map(|robot| {
+            let returning = robot.state.boundary_scout.as_ref().map(|s| s.returning).unwrap_or(false);
+            (robot.state.pose.position, returning)
+        })
+        .collect()
+}
+
+/// Splits `positions` into contiguous forward/returning runs and draws each as
+/// its own `<polyline>` (consecutive runs share their boundary point, so the
+/// path stays connected).
+fn write_trajectory(out: &mut impl Write, positions: &[(Point, bool)], style: &TrajectoryStyle) -> io::Result<()> {
+    if positions.len() < 2 {
+        return Ok(());
+    }
+
+    let mut start = 0;
+    while start < positions.len() - 1 {
+        let returning = positions[start + 1].1;
+        let mut end = start + 1;
+        while end < positions.len() - 1 && positions[end + 1].1 == returning {
+            end += 1;
+        }
+        write_polyline(out, &positions[start..=end], style, returning)?;
+        start = end;
+    }
+    Ok(())
+}
+
+fn write_polyline(out: &mut impl Write, segment: &[(Point, bool)], style: &TrajectoryStyle, returning: bool) -> io::Result<()> {
+    let points = segment
+        .iter()
+        .map(|(p, _)| format!("{:.1},{:.1}", p.x as f64 * CELL_SIZE + CELL_SIZE / 2.0, p.y as f64 * CELL_SIZE + CELL_SIZE / 2.0))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if returning {
+        if let Some(dash) = &style.returning_dash {
+            // Solid gap-colored underlay first, then the dashed stroke on top.
+            writeln!(
+                out,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linejoin="round" stroke-linecap="round"/>"#,
+                points, style.returning_gap_color, style.stroke_width
+            )?;
+            return writeln!(
+                out,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linejoin="round" stroke-linecap="round" stroke-dasharray="{}"/>"#,
+                points, style.stroke_color, style.stroke_width, dash
+            );
+        }
+    }
+
+    writeln!(
+        out,
+        r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{:.1}" stroke-linejoin="round" stroke-linecap="round"/>"#,
+        points, style.stroke_color, style.stroke_width
+    )
+}
+
+/// Writes one SVG file per tick (`{out_dir}/tick_{n:04}.svg`), suitable for
+/// assembling into an animation.
+pub fn export_svg_sequence(history: &[Vec<RobotNode>], out_dir: &str) -> io::Result<()> {
+    std::fs::create_dir_all(out_dir)?;
+    for tick in 0..history.len() {
+        let mut file = std::fs::File::create(format!("{}/tick_{:04}.svg", out_dir, tick))?;
+        export_svg(history, tick, &mut file)?;
+    }
+    Ok(())
+}
@@ -1,13 +1,19 @@
+use serde::{Deserialize, Serialize};
+
 /// Represents the state of a cell in the grid map.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum CellState {
     Unexplored,
     Empty,
     Obstacle,
+    /// A designated target cell. Passable like `Empty` for pathing and
+    /// wall-following purposes; `SimulationManager::tick` ends the run once
+    /// any robot's occupied cell is `Goal`.
+    Goal,
 }
 
 /// Represents the current operational phase of a robot.
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum RobotPhase {
     Idle,
     InitialWallFind,
@@ -16,6 +22,70 @@ pub enum RobotPhase {
     IslandEscape,
     CentralScan,
     InteriorSweep,
+    /// Covers open interiors by heading for the nearest unclaimed frontier
+    /// cluster instead of tracing walls. See `FrontierExplorationPhase`.
+    FrontierExploration,
+}
+
+/// One legal edge in the autonomous phase state machine, as declared in
+/// `robot_node::transition::TRANSITIONS`. `RobotNode::tick_autonomous` looks
+/// up the attempted `(from, to)` pair before applying it, so an undeclared
+/// jump (e.g. `InitialWallFind` straight to `InteriorSweep`) is rejected and
+/// logged instead of silently changing `RobotState::phase`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Transition {
+    pub id: u32,
+    pub label: &'static str,
+    pub from: RobotPhase,
+    pub to: RobotPhase,
+}
+
+/// Hand-written rather than derived: `label` is `&'static str`, and a
+/// derived `Deserialize<'de>` would require `'de: 'static`, which conflicts
+/// with the unconstrained `'de` that `RobotState`'s own derive needs for its
+/// `phase_history: Vec<(u32, Transition)>` field. Serializing just
+/// `id`/`from`/`to` and recovering `label` by looking the triple back up in
+/// `robot_node::transition::TRANSITIONS` sidesteps the lifetime entirely --
+/// `Transition` is just a `'static`-interned row of that table, never a
+/// value with data of its own worth persisting.
+impl Serialize for Transition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Transition", 3)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("to", &self.to)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Transition {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct TransitionIds {
+            id: u32,
+            from: RobotPhase,
+            to: RobotPhase,
+        }
+
+        let ids = TransitionIds::deserialize(deserializer)?;
+        crate::robot_node::transition::TRANSITIONS
+            .iter()
+            .find(|t| t.id == ids.id && t.from == ids.from && t.to == ids.to)
+            .copied()
+            .ok_or_else(|| {
+                serde::de::Error::custom(format!(
+                    "no declared transition {} ({:?} -> {:?})",
+                    ids.id, ids.from, ids.to
+                ))
+            })
+    }
 }
 
 /// Result of boundary analysis
@@ -26,31 +96,527 @@ pub enum BoundaryAnalysisResult {
     ExteriorWall, // Closed loop that touches map boundaries (room perimeter)
 }
 
+/// A single closed sub-loop's classification plus the area it encloses, from
+/// `BoundaryAnalyzer::classify_loop_winding`/`classify_sub_loops`. Kept
+/// separate from `BoundaryAnalysisResult` itself (rather than adding a field
+/// to it) since that enum's bare variants are pinned by existing equality
+/// assertions elsewhere.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LoopWinding {
+    pub result: BoundaryAnalysisResult,
+    /// Cells enclosed by the loop, via flood fill. Zero unless `result` is `Island`.
+    pub enclosed_cells: usize,
+}
+
 /// Simple 2D integer coordinates for the grid.
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
 }
 
 /// Robot's position and orientation (orientation as a simple 2D vector).
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct Pose {
     pub position: Point,
     pub orientation_rad: f64, // Angle in radians
 }
 
-/// The shared map representation.
-#[derive(Clone, Debug)]
-pub struct GridMap {
+/// Axis-aligned bounds of a grid, in cell coordinates.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Rect {
     pub width: usize,
     pub height: usize,
-    pub cells: Vec<CellState>,
-    // Optionally, add costmap or other fields as needed
+}
+
+impl Rect {
+    /// Returns true if `p` falls within `[0, width) x [0, height)`.
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+}
+
+/// One of the four cardinal movement directions. Replaces ad-hoc comparisons
+/// against rounded `orientation_rad` degrees with exhaustive enum matching, so
+/// wall-following's relative-direction tables can be built by chaining
+/// `turn_left`/`turn_right` instead of hand-writing four `(dx, dy)` tuples per
+/// rule.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// This direction's 90-degree step index (East = 0, South = 1, West = 2,
+    /// North = 3), matching the crate's existing clockwise step convention.
+    pub fn step_index(self) -> i32 {
+        match self {
+            Direction::East => 0,
+            Direction::South => 1,
+            Direction::West => 2,
+            Direction::North => 3,
+        }
+    }
+
+    fn from_step_index(step: i32) -> Direction {
+        match step.rem_euclid(4) {
+            0 => Direction::East,
+            1 => Direction::South,
+            2 => Direction::West,
+            _ => Direction::North,
+        }
+    }
+
+    /// The `(dx, dy)` unit step for this direction.
+    pub fn to_vector(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    /// Recovers a `Direction` from a `(dx, dy)` unit step, or `None` if it
+    /// isn't one of the four cardinal unit vectors.
+    pub fn from_vector(dx: i32, dy: i32) -> Option<Direction> {
+        match (dx, dy) {
+            (0, -1) => Some(Direction::North),
+            (0, 1) => Some(Direction::South),
+            (1, 0) => Some(Direction::East),
+            (-1, 0) => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// This direction's angle in radians, matching the crate's `*_RAD` constants.
+    pub fn to_rad(self) -> f64 {
+        match self {
+            Direction::East => crate::constants::EAST_RAD,
+            Direction::South => crate::constants::SOUTH_RAD,
+            Direction::West => crate::constants::WEST_RAD,
+            Direction::North => crate::constants::NORTH_RAD,
+        }
+    }
+
+    /// Recovers the nearest cardinal `Direction` from an angle in radians,
+    /// rounding to the nearest 90-degree step. Always returns a valid
+    /// direction -- there is no "non-cardinal" case to warn about.
+    pub fn from_rad(orientation_rad: f64) -> Direction {
+        let normalized_deg = orientation_rad.rem_euclid(2.0 * std::f64::consts::PI).to_degrees();
+        Direction::from_step_index((normalized_deg / 90.0).round() as i32)
+    }
+
+    /// The direction 90 degrees counterclockwise from this one.
+    pub fn turn_left(self) -> Direction {
+        Direction::from_step_index(self.step_index() - 1)
+    }
+
+    /// The direction 90 degrees clockwise from this one.
+    pub fn turn_right(self) -> Direction {
+        Direction::from_step_index(self.step_index() + 1)
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Direction {
+        Direction::from_step_index(self.step_index() + 2)
+    }
+
+    /// The signed rotation, in 90-degree steps, from this direction to
+    /// `other`, in `[-2, 2]` and taking the shorter way around.
+    pub fn rotation_steps_to(self, other: Direction) -> i32 {
+        let mut diff = other.step_index() - self.step_index();
+        if diff > 2 {
+            diff -= 4;
+        } else if diff < -2 {
+            diff += 4;
+        }
+        diff
+    }
+}
+
+/// A directed edge between two map boundaries: stepping off `boundary_cell` in
+/// `exit_direction` teleports the robot to `entry_cell`, facing `entry_direction`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Portal {
+    pub boundary_cell: Point,
+    pub exit_direction: Point,
+    pub entry_cell: Point,
+    pub entry_direction: Point,
+}
+
+impl Portal {
+    /// The portal a robot would take to step back the way it came.
+    pub fn reverse(&self) -> Portal {
+        Portal {
+            boundary_cell: self.entry_cell,
+            exit_direction: Point { x: -self.entry_direction.x, y: -self.entry_direction.y },
+            entry_cell: self.boundary_cell,
+            entry_direction: Point { x: -self.exit_direction.x, y: -self.exit_direction.y },
+        }
+    }
+}
+
+/// The connectivity of a grid's edges, so wall-following and boundary-scouting
+/// can operate on non-rectangular worlds instead of always hitting a closed box.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Topology {
+    /// The map edge is an impassable wall (the historical, and still default, behavior).
+    Bounded,
+    /// Stepping off one edge re-enters on the opposite edge.
+    Toroidal,
+    /// Stepping off specific boundary cells teleports through a named portal.
+    Portals(Vec<Portal>),
+}
+
+impl Topology {
+    /// Builds a `Portals` topology from one-directional portal definitions,
+    /// auto-generating the matching reverse portal for each so stepping back
+    /// through an exit returns the robot the way it came.
+    pub fn portals(one_way: Vec<Portal>) -> Topology {
+        let mut all = one_way.clone();
+        all.extend(one_way.iter().map(Portal::reverse));
+        Topology::Portals(all)
+    }
+}
+
+/// A generic row-major 2D grid of cells, indexed by `Point`.
+///
+/// Centralizes the `y*width+x` bounds arithmetic that used to be duplicated across
+/// `BoundaryAnalyzer`, `map_manager`, and the tests -- a frequent source of
+/// off-by-one bugs at the `width-1`/`height-1` edges. Also usable for auxiliary
+/// grids (distance fields, visit-count maps) that share the same cell layout as
+/// the map but a different value type.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+    /// Connectivity of this grid's edges. Defaults to `Bounded` via `Grid::new`.
+    pub topology: Topology,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid with the default `Bounded` topology.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        Self { width, height, cells, topology: Topology::Bounded }
+    }
+
+    /// This grid's bounds as a `Rect`.
+    pub fn bounds(&self) -> Rect {
+        Rect { width: self.width, height: self.height }
+    }
+
+    /// Converts a coordinate to a flat index, or `None` if out of bounds.
+    pub fn coord_to_index(&self, p: Point) -> Option<usize> {
+        if !self.bounds().contains(p) {
+            return None;
+        }
+        Some((p.y as usize) * self.width + (p.x as usize))
+    }
+
+    /// Converts a flat index back to its coordinate.
+    pub fn index_to_coord(&self, idx: usize) -> Point {
+        Point { x: (idx % self.width) as i32, y: (idx / self.width) as i32 }
+    }
+
+    /// Returns a reference to the cell at `p`, or `None` if out of bounds.
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.coord_to_index(p).map(|idx| &self.cells[idx])
+    }
+
+    /// Returns a mutable reference to the cell at `p`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        let idx = self.coord_to_index(p)?;
+        Some(&mut self.cells[idx])
+    }
+
+    /// Sets the cell at `p` to `value`. Returns false (no-op) if out of bounds.
+    pub fn set(&mut self, p: Point, value: T) -> bool {
+        match self.coord_to_index(p) {
+            Some(idx) => {
+                self.cells[idx] = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over every `(Point, &T)` in row-major order.
+    pub fn iter_coords(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(move |(idx, cell)| (self.index_to_coord(idx), cell))
+    }
+
+    /// Resolves a candidate position `p` (which may lie outside `[0, width) x
+    /// [0, height)`) against this grid's `Topology`. In-bounds points pass
+    /// straight through; `Toroidal` wraps the out-of-bounds coordinate back
+    /// onto the opposite edge using `lower + (pos - lower + range) % range` on
+    /// each axis. Returns `None` only when no wrap applies, so the caller
+    /// should fall back to treating the step as blocked.
+    ///
+    /// `Portals` moves aren't resolved here since a portal also depends on
+    /// which direction the robot stepped, not just the landing coordinate --
+    /// see `resolve_portal`.
+    pub fn wrap_position(&self, p: Point) -> Option<Point> {
+        if self.bounds().contains(p) {
+            return Some(p);
+        }
+
+        match self.topology {
+            Topology::Toroidal => {
+                let width = self.width as i32;
+                let height = self.height as i32;
+                Some(Point {
+                    x: p.x.rem_euclid(width),
+                    y: p.y.rem_euclid(height),
+                })
+            }
+            Topology::Bounded | Topology::Portals(_) => None,
+        }
+    }
+
+    /// Looks up the portal a robot standing on `boundary_cell` takes when
+    /// stepping in `exit_direction`, returning the `(entry_cell,
+    /// entry_direction)` it lands on. Only meaningful when `topology` is
+    /// `Topology::Portals`.
+    pub fn resolve_portal(&self, boundary_cell: Point, exit_direction: Point) -> Option<(Point, Point)> {
+        match &self.topology {
+            Topology::Portals(portals) => portals
+                .iter()
+                .find(|portal| portal.boundary_cell == boundary_cell && portal.exit_direction == exit_direction)
+                .map(|portal| (portal.entry_cell, portal.entry_direction)),
+            Topology::Bounded | Topology::Toroidal => None,
+        }
+    }
+}
+
+/// The shared map representation: a grid of cell states.
+pub type GridMap = Grid<CellState>;
+
+impl GridMap {
+    /// Shortest route from `from` to `to` through known-free (`CellState::Empty`)
+    /// cells: a BFS distance flood outward from `to`, then gradient-descent
+    /// from `from` always stepping to the 4-connected neighbor with the
+    /// smallest distance value. Recomputed from scratch on every call (rather
+    /// than cached) so a newly discovered wall can never leave a stale route
+    /// through it. Returns `None` if `to` is unreachable from `from` through
+    /// known-free cells, including when either endpoint isn't itself `Empty`.
+    pub fn floodfill_path(&self, from: Point, to: Point) -> Option<Vec<Point>> {
+        const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        if self.get(from) != Some(&CellState::Empty) || self.get(to) != Some(&CellState::Empty) {
+            return None;
+        }
+
+        let mut distance = vec![u32::MAX; self.cells.len()];
+        distance[self.coord_to_index(to)?] = 0;
+
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(to);
+
+        while let Some(pos) = queue.pop_front() {
+            let pos_dist = distance[self.coord_to_index(pos).unwrap()];
+
+            for (dx, dy) in FOUR_NEIGHBORS {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                let neighbor_idx = match self.coord_to_index(neighbor) {
+                    Some(idx) => idx,
+                    None => continue,
+                };
+                if self.cells[neighbor_idx] != CellState::Empty {
+                    continue;
+                }
+                if distance[neighbor_idx] == u32::MAX {
+                    distance[neighbor_idx] = pos_dist + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        let from_idx = self.coord_to_index(from)?;
+        if distance[from_idx] == u32::MAX {
+            return None;
+        }
+
+        let mut path = vec![from];
+        let mut current = from;
+        while current != to {
+            let current_dist = distance[self.coord_to_index(current).unwrap()];
+            let (next, _) = FOUR_NEIGHBORS.iter()
+                .map(|&(dx, dy)| Point { x: current.x + dx, y: current.y + dy })
+                .filter_map(|p| self.coord_to_index(p).map(|idx| (p, distance[idx])))
+                .filter(|&(_, d)| d < current_dist)
+                .min_by_key(|&(_, d)| d)?;
+            current = next;
+            path.push(current);
+        }
+
+        Some(path)
+    }
+
+    /// Wavefront navigation function: a BFS distance grid seeded at `sources`
+    /// (distance 0) and propagated outward through `Empty`/`Unexplored` cells,
+    /// blocked by `Obstacle`. A cell's value is the number of 4-connected
+    /// steps to the nearest source, or `i32::MAX` if unreachable -- a robot
+    /// descending it by always stepping to the lowest-valued neighbor makes
+    /// monotonic progress toward the nearest source with no local minima,
+    /// unlike hill-climbing on raw Manhattan distance to a single assigned
+    /// point. `InteriorSweepPhase` recomputes it per robot, seeded at that
+    /// robot's own assigned target.
+    pub fn compute_wavefront(&self, sources: &[Point]) -> Vec<i32> {
+        const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        let mut distance = vec![i32::MAX; self.cells.len()];
+        let mut queue = std::collections::VecDeque::new();
+
+        for &source in sources {
+            if let Some(idx) = self.coord_to_index(source) {
+                if distance[idx] == i32::MAX {
+                    distance[idx] = 0;
+                    queue.push_back(source);
+                }
+            }
+        }
+
+        while let Some(pos) = queue.pop_front() {
+            let pos_dist = distance[self.coord_to_index(pos).unwrap()];
+
+            for (dx, dy) in FOUR_NEIGHBORS {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                let neighbor_idx = match self.coord_to_index(neighbor) {
+                    Some(idx) => idx,
+                    None => continue,
+                };
+                if self.cells[neighbor_idx] == CellState::Obstacle {
+                    continue;
+                }
+                if distance[neighbor_idx] == i32::MAX {
+                    distance[neighbor_idx] = pos_dist + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Obstacle-clearance distance transform: for every cell, the number of
+    /// 4-connected steps to the nearest `Obstacle` cell or the map boundary
+    /// (whichever is closer), via a multi-source BFS seeded at obstacles
+    /// (distance 0) and at border cells (distance 1, since stepping off the
+    /// map is itself wall-like). Used by the A* planner's clearance penalty
+    /// so routes can trade a little extra length for more margin from walls
+    /// instead of treating every free cell as equally safe.
+    pub fn distance_transform(&self) -> Vec<u32> {
+        const FOUR_NEIGHBORS: [(i32, i32); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+        let mut distance = vec![u32::MAX; self.cells.len()];
+        let mut queue = std::collections::VecDeque::new();
+
+        for (idx, &cell) in self.cells.iter().enumerate() {
+            if cell == CellState::Obstacle {
+                distance[idx] = 0;
+                queue.push_back(idx);
+            }
+        }
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let on_border = x == 0 || y == 0 || x == self.width as i32 - 1 || y == self.height as i32 - 1;
+                if !on_border {
+                    continue;
+                }
+                let idx = self.coord_to_index(Point { x, y }).unwrap();
+                if distance[idx] == u32::MAX {
+                    distance[idx] = 1;
+                    queue.push_back(idx);
+                }
+            }
+        }
+
+        while let Some(idx) = queue.pop_front() {
+            let pos = Point { x: (idx % self.width) as i32, y: (idx / self.width) as i32 };
+            let pos_dist = distance[idx];
+
+            for (dx, dy) in FOUR_NEIGHBORS {
+                let neighbor = Point { x: pos.x + dx, y: pos.y + dy };
+                let neighbor_idx = match self.coord_to_index(neighbor) {
+                    Some(idx) => idx,
+                    None => continue,
+                };
+                if distance[neighbor_idx] == u32::MAX {
+                    distance[neighbor_idx] = pos_dist + 1;
+                    queue.push_back(neighbor_idx);
+                }
+            }
+        }
+
+        distance
+    }
+}
+
+/// Policy governing what happens when a planned step would leave the map bounds.
+/// Previously this was conflated with "exterior wall" everywhere; making it
+/// explicit lets the crate model open-edge arenas (`Absorb`), bounded rooms
+/// (`Reflect`), and periodic test worlds (`Wrap`) distinctly.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BoundaryCondition {
+    /// Clamp the move and reject it -- the map edge behaves like a wall.
+    Absorb,
+    /// Bounce the heading back the way it came, as if off a mirror.
+    Reflect,
+    /// Toroidal wraparound: stepping off one edge re-enters on the opposite edge.
+    Wrap,
+}
+
+/// How many neighboring cells a robot may step into. Diagonal moves under
+/// `Eight` still require both orthogonal cells between the current and target
+/// cell to be free, so robots can't clip through an obstacle corner.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Connectivity {
+    /// Only the four cardinal neighbors are reachable in one step.
+    Four,
+    /// The four cardinal neighbors plus the four diagonals are reachable.
+    Eight,
+}
+
+/// Emitted by `map_manager::apply_boundary_condition` whenever a step attempted
+/// to cross the map edge, so callers can observe boundary interactions.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BoundaryHitEvent {
+    pub attempted: Point,
+    pub condition: BoundaryCondition,
+}
+
+/// Which side of the wall a boundary-scouting tracer keeps itself on, chosen
+/// per scouting mission. `BoundaryScoutState.tracing_direction` (and the
+/// rotation bookkeeping that reads it) still stores the underlying ±1
+/// convention (`LEFT_HAND_RULE`/`RIGHT_HAND_RULE`); this enum exists so a
+/// caller can pick a hand-rule explicitly via `RobotState::preferred_wall_follow`
+/// -- e.g. to start two partners on opposite rules so they race around a
+/// boundary from both sides -- instead of only getting whichever rule the
+/// first-move turn-away-from-partner heuristic happens to settle on.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum WallFollow {
+    LeftHand,
+    RightHand,
+}
+
+impl WallFollow {
+    /// The ±1 convention (`LEFT_HAND_RULE`/`RIGHT_HAND_RULE`) the rest of the
+    /// wall-following and rotation-bookkeeping code is written in terms of.
+    pub fn tracing_direction(self) -> i8 {
+        match self {
+            WallFollow::LeftHand => crate::constants::LEFT_HAND_RULE,
+            WallFollow::RightHand => crate::constants::RIGHT_HAND_RULE,
+        }
+    }
 }
 
 /// State for boundary scouting phase
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BoundaryScoutState {
     pub tracing_direction: i8, // -1 for left, +1 for right
     pub steps_taken: u32, // Total steps taken in the current phase (BoundaryScouting)
@@ -63,15 +629,23 @@ pub struct BoundaryScoutState {
 }
 
 /// State for central scan phase
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CentralScanState {
     pub virtual_boundary: Vec<Point>,     // Previous loop becomes new "wall"
     pub scan_iteration: u32,              // How many layers deep we've gone
     pub completed_loops: Vec<Vec<Point>>, // All completed boundary loops
 }
 
+/// State for `FrontierExplorationPhase`: which frontier cluster centroid this
+/// robot is currently heading for, so its partner can see the claim and avoid
+/// re-targeting the same cluster.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FrontierExplorationState {
+    pub target: Option<Point>,
+}
+
 /// State information for a single robot.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RobotState {
     pub id: u8,
     pub pose: Pose,
@@ -84,10 +658,49 @@ pub struct RobotState {
     pub travel_direction_before_island: Option<f64>,
     pub boundary_scout: Option<BoundaryScoutState>,
     pub central_scan: Option<CentralScanState>,
+    pub frontier_exploration: Option<FrontierExplorationState>,
+    /// Probability of repeating `last_wall_find_direction` during InitialWallFind
+    /// instead of re-choosing, to damp zig-zag jitter on open maps.
+    pub momentum_prob: f64,
+    /// Last direction stepped during InitialWallFind, used as the momentum bias.
+    pub last_wall_find_direction: Option<Point>,
+    /// How many neighboring cells this robot may step into. Only honored by
+    /// the goal-directed/escape/sweep movement helpers that plan their own
+    /// step set (`IslandEscapePhase`, `InteriorSweepPhase`, `path_planner`);
+    /// wall-following's cardinal-only quarter-turn tracing is left alone
+    /// since boundary analysis's ±4 turning-number invariant depends on it.
+    pub connectivity: Connectivity,
+    /// Hand-rule a boundary-scouting mission should commit to, overriding the
+    /// turn-away-from-partner heuristic that would otherwise pick one after
+    /// the first move. `None` keeps the existing auto-detected behavior.
+    pub preferred_wall_follow: Option<WallFollow>,
+    /// Frontier cluster centroid this robot won in the last
+    /// `PhaseContext::auction_frontier_clusters` resolution -- the
+    /// cluster-level analogue of `FrontierExplorationState::target`, used by
+    /// `InteriorSweepPhase` so task allocation scales to any number of
+    /// participating robots instead of a single hard-coded partner.
+    pub assigned_frontier_goal: Option<Point>,
+    /// Number of times `RobotNode::tick_autonomous` has run for this robot,
+    /// used as the timestamp in `phase_history` entries.
+    pub tick_count: u32,
+    /// Rolling log of `(tick_count, Transition)` phase changes this robot
+    /// has made, capped at `PHASE_HISTORY_LIMIT` entries (oldest dropped
+    /// first). Lets the final report print each robot's phase timeline
+    /// instead of the `println!`s that used to track phase changes inline.
+    pub phase_history: Vec<(u32, Transition)>,
+}
+
+/// A connected cluster of frontier cells (Empty cells bordering Unexplored space),
+/// as produced by `map_manager::cluster_frontiers`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frontier {
+    pub centroid: Point,
+    pub cells: Vec<Point>,
+    pub size: usize,
 }
 
 /// Data collected during a boundary trace to analyze a closed loop.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LoopAnalysisData {
     pub path_traced: Vec<Point>,
     pub total_angular_displacement: f64,
@@ -96,5 +709,4 @@ pub struct LoopAnalysisData {
     pub loop_closed: Option<bool>,
     pub total_loop_length: Option<u32>,
     pub midpoint_direction: Option<Point>,
-    pub target_position: Option<Point>,
-} 
\ No newline at end of file
+}
\ No newline at end of file
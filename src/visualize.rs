@@ -132,6 +132,20 @@ impl App for VisualizeApp {
                                     egui::Color32::WHITE,
                                 );
                             }
+                            CellState::Goal => {
+                                let x0 = map_rect.left_top().x + x as f32 * 20.0;
+                                let y0 = map_rect.left_top().y + y as f32 * 20.0;
+                                let x1 = x0 + 20.0;
+                                let y1 = y0 + 20.0;
+                                painter.rect_filled(
+                                    egui::Rect::from_min_max(
+                                        egui::pos2(x0, y0),
+                                        egui::pos2(x1, y1),
+                                    ),
+                                    0.0,
+                                    egui::Color32::from_rgb(46, 204, 113),
+                                );
+                            }
                             CellState::Unexplored => {}
                         }
                     }
@@ -0,0 +1,137 @@
+/// A deterministic multi-agent simulation driver, complementing
+/// `SimulationManager`'s `Vec<RobotNode>` + direct-tick approach with an
+/// explicit scheduler: robots are keyed by id in a `HashMap`, each gets a
+/// read-only `WorldView` instead of the full robot list, and `do_step`
+/// resolves destination conflicts before committing so two robots can never
+/// end a tick standing on the same cell -- making emergent behaviors
+/// (boundary rendezvous, island classification) reproducible from a seed
+/// rather than depending on iteration order.
+///
+/// Each robot's own decision-making still runs through `RobotNode::tick`'s
+/// existing contract (preserved as-is, since it's exercised directly by
+/// existing tests) rather than a from-scratch propose/commit split of every
+/// phase. Because of that, a robot whose destination turns out to be
+/// contested has its *entire* tick discarded, not just its final position --
+/// simpler and still deterministic, at the cost of that robot not advancing
+/// any phase-internal bookkeeping (e.g. scout path/rotation tracking) on a
+/// tick where it loses a collision.
+
+use std::collections::HashMap;
+
+use crate::constants::{PHEROMONE_DECAY, PHEROMONE_DEPOSIT};
+use crate::robot_node::{sensing, RobotNode};
+use crate::types::*;
+
+/// Identifies a robot within a `World`. Matches `RobotState::id`.
+pub type RobotId = u8;
+
+/// A read-only snapshot of the world as seen by one robot for a single tick --
+/// what `partner_id`/`last_known_partner_pose` on `RobotState` give implicit
+/// access to, made explicit and owned by the scheduler instead of the robot.
+#[derive(Clone, Debug)]
+pub struct WorldView {
+    pub self_id: RobotId,
+    pub self_pose: Pose,
+    pub last_known_partner_pose: Option<Pose>,
+    pub visible_cells: Vec<(Point, CellState)>,
+}
+
+/// A robot's proposed action for the current tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Move {
+    StepTo(Point),
+    Stay,
+}
+
+/// Owns every robot and the ground-truth map, and steps them together one
+/// tick at a time.
+pub struct World {
+    pub map: GridMap,
+    pub robots: HashMap<RobotId, RobotNode>,
+    /// Shared stigmergic trail layer, parallel to `map.cells`. See
+    /// `SimulationManager::pheromone`; unlike there, deposit/decay rates
+    /// aren't independently configurable here and just use
+    /// `PHEROMONE_DEPOSIT`/`PHEROMONE_DECAY` directly.
+    pub pheromone: Vec<f32>,
+}
+
+impl World {
+    /// Builds a `World` from the ground-truth map and an initial robot set.
+    pub fn new(map: GridMap, robots: impl IntoIterator<Item = RobotNode>) -> Self {
+        let robots = robots.into_iter().map(|robot| (robot.state.id, robot)).collect();
+        let pheromone = vec![0.0; map.cells.len()];
+        Self { map, robots, pheromone }
+    }
+
+    /// The read-only view a robot has of the world before proposing a move
+    /// this tick.
+    pub fn view_for(&self, id: RobotId) -> Option<WorldView> {
+        let robot = self.robots.get(&id)?;
+        let last_known_partner_pose = self.robots.get(&robot.state.partner_id).map(|partner| partner.state.pose);
+        let visible_cells = sensing::compute_visible_cells(&robot.state.pose, &self.map);
+
+        Some(WorldView {
+            self_id: id,
+            self_pose: robot.state.pose,
+            last_known_partner_pose,
+            visible_cells,
+        })
+    }
+
+    /// Advances the world by one tick: every robot decides its move against a
+    /// shared, fixed snapshot of the tick's starting state (so a robot never
+    /// sees another's *result* from later in this same tick), conflicting
+    /// destinations are detected, and only non-conflicting moves are
+    /// committed.
+    pub fn do_step(&mut self) {
+        let mut ids: Vec<RobotId> = self.robots.keys().copied().collect();
+        ids.sort();
+
+        let snapshot: Vec<RobotNode> = ids.iter().filter_map(|id| self.robots.get(id).cloned()).collect();
+
+        let mut pheromone = self.pheromone.clone();
+        let proposals: HashMap<RobotId, RobotNode> = ids.iter()
+            .map(|&id| {
+                let mut probe = self.robots[&id].clone();
+                probe.tick(&snapshot, &self.map, &mut pheromone, PHEROMONE_DEPOSIT);
+                (id, probe)
+            })
+            .collect();
+        for cell in &mut pheromone {
+            *cell *= PHEROMONE_DECAY;
+        }
+        self.pheromone = pheromone;
+
+        // A cell more than one robot would land on this tick, or a cell another
+        // robot starts the tick on, is contested; every robot proposing it stays
+        // put instead of racing on iteration order.
+        let starts: Vec<(RobotId, Point)> = ids.iter().map(|&id| (id, self.robots[&id].state.pose.position)).collect();
+        let mut destination_counts: HashMap<Point, u32> = HashMap::new();
+        for (&id, proposal) in &proposals {
+            let original = self.robots[&id].state.pose.position;
+            let dest = proposal.state.pose.position;
+            if dest != original {
+                *destination_counts.entry(dest).or_insert(0) += 1;
+            }
+        }
+
+        for &id in &ids {
+            let original = self.robots[&id].state.pose.position;
+            let proposal = &proposals[&id];
+            let dest = proposal.state.pose.position;
+            let occupied = starts.iter().any(|&(other, pos)| other != id && pos == dest);
+            let contested = dest != original && (destination_counts.get(&dest).copied().unwrap_or(0) > 1 || occupied);
+
+            if !contested {
+                self.robots.insert(id, proposal.clone());
+            }
+        }
+    }
+
+    /// The `Move` a robot proposed on its most recent `do_step`, by comparing
+    /// its committed position against `from`.
+    pub fn move_from(&self, id: RobotId, from: Point) -> Option<Move> {
+        let current = self.robots.get(&id)?.state.pose.position;
+        Some(if current == from { Move::Stay } else { Move::StepTo(current) })
+    }
+}
@@ -1,5 +1,6 @@
 use multiagent_explore::simulation_manager::SimulationManager;
 use multiagent_explore::types::*;
+use multiagent_explore::constants::*;
 use std::collections::HashSet;
 
 /// Helper function to create a test map from ASCII representation
@@ -18,7 +19,7 @@ fn create_test_map(ascii_grid: &[&str]) -> GridMap {
             };
         }
     }
-    GridMap { width, height, cells: map_cells }
+    GridMap::new(width, height, map_cells)
 }
 
 /// Test that robots can complete exploration of a simple corridor
@@ -51,7 +52,7 @@ fn test_simple_corridor_exploration() {
             for other in &robots_snapshot {
                 if other.state.id != robot.state.id && 
                    multiagent_explore::robot_node::RobotNode::within_comm_range(&robot.state.pose.position, &other.state.pose.position) {
-                    robot.merge_map(&other.state.map);
+                    robot.merge_map(&other.state.map, other.state.pose);
                 }
             }
         }
@@ -59,7 +60,7 @@ fn test_simple_corridor_exploration() {
         // Execute robot logic
         let robots_snapshot = sim.robots.clone();
         for robot in &mut sim.robots {
-            robot.tick(&robots_snapshot, &sim.map);
+            robot.tick(&robots_snapshot, &sim.map, &mut sim.pheromone, sim.pheromone_deposit);
         }
         
         // Check if robots reached boundary phase (indicating successful initial exploration)
@@ -112,7 +113,7 @@ fn test_island_detection() {
             for other in &robots_snapshot {
                 if other.state.id != robot.state.id && 
                    multiagent_explore::robot_node::RobotNode::within_comm_range(&robot.state.pose.position, &other.state.pose.position) {
-                    robot.merge_map(&other.state.map);
+                    robot.merge_map(&other.state.map, other.state.pose);
                 }
             }
         }
@@ -120,7 +121,7 @@ fn test_island_detection() {
         // Execute robot logic
         let robots_snapshot = sim.robots.clone();
         for robot in &mut sim.robots {
-            robot.tick(&robots_snapshot, &sim.map);
+            robot.tick(&robots_snapshot, &sim.map, &mut sim.pheromone, sim.pheromone_deposit);
         }
         
         // Check if robots have encountered boundary analysis phase
@@ -170,7 +171,7 @@ fn test_communication_during_scouting() {
                 if other.state.id != robot.state.id && 
                    multiagent_explore::robot_node::RobotNode::within_comm_range(&robot.state.pose.position, &other.state.pose.position) {
                     communication_events += 1;
-                    robot.merge_map(&other.state.map);
+                    robot.merge_map(&other.state.map, other.state.pose);
                 }
             }
         }
@@ -178,7 +179,7 @@ fn test_communication_during_scouting() {
         // Execute robot logic
         let robots_snapshot = sim.robots.clone();
         for robot in &mut sim.robots {
-            robot.tick(&robots_snapshot, &sim.map);
+            robot.tick(&robots_snapshot, &sim.map, &mut sim.pheromone, sim.pheromone_deposit);
         }
     }
     
@@ -195,11 +196,7 @@ fn create_test_robot_state(id: u8, position: Point, partner_id: u8, map: &GridMa
             orientation_rad: -std::f64::consts::PI / 2.0 // Facing North
         },
         phase: RobotPhase::InitialWallFind,
-        map: GridMap {
-            width: map.width,
-            height: map.height,
-            cells: vec![CellState::Unexplored; map.width * map.height],
-        },
+        map: GridMap::new(map.width, map.height, vec![CellState::Unexplored; map.width * map.height]),
         scout_depth_n: 3,
         partner_id,
         last_known_partner_pose: None,
@@ -207,6 +204,14 @@ fn create_test_robot_state(id: u8, position: Point, partner_id: u8, map: &GridMa
         travel_direction_before_island: None,
         boundary_scout: None,
         central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
     }
 }
 
@@ -239,7 +244,7 @@ fn test_exploration_coverage() {
             for other in &robots_snapshot {
                 if other.state.id != robot.state.id && 
                    multiagent_explore::robot_node::RobotNode::within_comm_range(&robot.state.pose.position, &other.state.pose.position) {
-                    robot.merge_map(&other.state.map);
+                    robot.merge_map(&other.state.map, other.state.pose);
                 }
             }
         }
@@ -247,7 +252,7 @@ fn test_exploration_coverage() {
         // Execute robot logic
         let robots_snapshot = sim.robots.clone();
         for robot in &mut sim.robots {
-            robot.tick(&robots_snapshot, &sim.map);
+            robot.tick(&robots_snapshot, &sim.map, &mut sim.pheromone, sim.pheromone_deposit);
         }
     }
     
@@ -1,5 +1,6 @@
 use multiagent_explore::robot_node::RobotNode;
 use multiagent_explore::robot_node::wall_following::WallFollower;
+use multiagent_explore::robot_node::phase_trait::PhaseContext;
 use multiagent_explore::types::*;
 use multiagent_explore::constants::*;
 use std::f64::consts::PI;
@@ -20,7 +21,7 @@ fn create_test_map(ascii_grid: &[&str]) -> GridMap {
             };
         }
     }
-    GridMap { width, height, cells: map_cells }
+    GridMap::new(width, height, map_cells)
 }
 
 #[test]
@@ -56,6 +57,15 @@ fn test_robot0_wall_follow_left_hand_simple_wall() {
             initial_scouting_direction: None,
             total_rotation_steps: 0,
         }),
+        central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
     };
     let mut robot0 = RobotNode::new(robot0_state);
 
@@ -128,6 +138,15 @@ fn test_robot1_wall_follow_right_hand_simple_wall() {
             initial_scouting_direction: None,
             total_rotation_steps: 0,
         }),
+        central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
     };
     let mut robot1 = RobotNode::new(robot1_state);
 
@@ -190,6 +209,15 @@ fn test_rotation_based_boundary_analysis() {
             initial_scouting_direction: None,
             total_rotation_steps: -2, // Robot 0 rotated -2 steps (clockwise)
         }),
+        central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
     };
 
     let robot1_state = RobotState {
@@ -212,6 +240,15 @@ fn test_rotation_based_boundary_analysis() {
             initial_scouting_direction: None,
             total_rotation_steps: 2, // Robot 1 rotated +2 steps (counter-clockwise)
         }),
+        central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
     };
 
     // Test exterior wall case: -2 - 2 = -4 (exterior wall)
@@ -226,4 +263,230 @@ fn test_rotation_based_boundary_analysis() {
     // Test incomplete case: missing data
     let result = BoundaryAnalyzer::analyze_boundary_by_rotation(None, Some(2));
     assert_eq!(result, BoundaryAnalysisResult::Incomplete);
+}
+
+#[test]
+fn test_boundary_analysis_island_detection() {
+    use multiagent_explore::robot_node::boundary_analysis::BoundaryAnalyzer;
+
+    // Test case 1: Small island (obstacle) - closed loop not touching boundaries
+    let island_path = vec![
+        Point { x: 5, y: 5 },   // Starting point
+        Point { x: 6, y: 5 },   // Right
+        Point { x: 6, y: 6 },   // Down
+        Point { x: 5, y: 6 },   // Left
+        Point { x: 5, y: 5 },   // Back to start - closed loop
+    ];
+
+    let map = GridMap::new(20, 20, vec![CellState::Empty; 20 * 20]);
+    assert!(BoundaryAnalyzer::is_boundary_closed_loop(&island_path));
+    assert!(BoundaryAnalyzer::is_island_not_exterior(&island_path, &map));
+
+    // Test case 2: Exterior wall - touches map boundaries
+    let exterior_path = vec![
+        Point { x: 0, y: 5 },   // Starting at left boundary
+        Point { x: 0, y: 6 },
+        Point { x: 1, y: 6 },
+        Point { x: 1, y: 5 },
+        Point { x: 0, y: 5 },   // Back to start - closed loop touching boundary
+    ];
+
+    assert!(BoundaryAnalyzer::is_boundary_closed_loop(&exterior_path));
+    assert!(!BoundaryAnalyzer::is_island_not_exterior(&exterior_path, &map));
+
+    // Test case 3: Open path (shouldn't happen in normal operation)
+    let open_path = vec![
+        Point { x: 5, y: 5 },
+        Point { x: 6, y: 5 },
+        Point { x: 6, y: 6 },
+    ];
+
+    assert!(!BoundaryAnalyzer::is_boundary_closed_loop(&open_path));
+}
+
+#[test]
+fn test_boundary_analysis_edge_cases() {
+    use multiagent_explore::robot_node::boundary_analysis::BoundaryAnalyzer;
+
+    let map = GridMap::new(10, 10, vec![CellState::Empty; 10 * 10]);
+
+    // Test: Path touching right boundary
+    let right_boundary_path = vec![
+        Point { x: 9, y: 3 },   // Right boundary (x = width-1)
+        Point { x: 9, y: 4 },
+        Point { x: 8, y: 4 },
+        Point { x: 8, y: 3 },
+        Point { x: 9, y: 3 },
+    ];
+
+    assert!(BoundaryAnalyzer::is_boundary_closed_loop(&right_boundary_path));
+    assert!(!BoundaryAnalyzer::is_island_not_exterior(&right_boundary_path, &map));
+
+    // Test: Path touching bottom boundary
+    let bottom_boundary_path = vec![
+        Point { x: 3, y: 9 },   // Bottom boundary (y = height-1)
+        Point { x: 4, y: 9 },
+        Point { x: 4, y: 8 },
+        Point { x: 3, y: 8 },
+        Point { x: 3, y: 9 },
+    ];
+
+    assert!(BoundaryAnalyzer::is_boundary_closed_loop(&bottom_boundary_path));
+    assert!(!BoundaryAnalyzer::is_island_not_exterior(&bottom_boundary_path, &map));
+}
+
+fn make_interior_sweep_robot_state(id: u8, position: Point, partner_id: u8, map: &GridMap) -> RobotState {
+    RobotState {
+        id,
+        pose: Pose { position, orientation_rad: -PI / 2.0 },
+        phase: RobotPhase::InteriorSweep,
+        map: GridMap::new(map.width, map.height, vec![CellState::Unexplored; map.width * map.height]),
+        scout_depth_n: 3,
+        partner_id,
+        last_known_partner_pose: None,
+        loop_analysis_data: None,
+        travel_direction_before_island: None,
+        boundary_scout: None,
+        central_scan: None,
+        frontier_exploration: None,
+        momentum_prob: DEFAULT_MOMENTUM_PROB,
+        last_wall_find_direction: None,
+        connectivity: Connectivity::Four,
+        preferred_wall_follow: None,
+        assigned_frontier_goal: None,
+        tick_count: 0,
+        phase_history: Vec::new(),
+    }
+}
+
+#[test]
+fn test_auction_frontier_clusters_assigns_each_robot_its_nearer_cluster() {
+    // Corridor wide enough for two robots and two frontier clusters, one
+    // near each robot -- generalizes the old two-robot "nearest unclaimed
+    // centroid" assignment to an arbitrary `participants` list.
+    let map = create_test_map(&[
+        ".........",
+        ".........",
+        ".........",
+    ]);
+
+    let robot0 = RobotNode::new(make_interior_sweep_robot_state(ROBOT_LEFT_HAND, Point { x: 1, y: 1 }, ROBOT_RIGHT_HAND, &map));
+    let robot1 = RobotNode::new(make_interior_sweep_robot_state(ROBOT_RIGHT_HAND, Point { x: 7, y: 1 }, ROBOT_LEFT_HAND, &map));
+    let all_robots = vec![robot0, robot1];
+
+    let pheromone = vec![0.0f32; map.cells.len()];
+    let context = PhaseContext {
+        all_robots: &all_robots,
+        global_map: &map,
+        pheromone: &pheromone,
+    };
+
+    let frontiers = vec![
+        Frontier { centroid: Point { x: 0, y: 1 }, cells: vec![Point { x: 0, y: 1 }], size: 1 },
+        Frontier { centroid: Point { x: 8, y: 1 }, cells: vec![Point { x: 8, y: 1 }], size: 1 },
+    ];
+
+    let assignments = context.auction_frontier_clusters(&[ROBOT_LEFT_HAND, ROBOT_RIGHT_HAND], &frontiers);
+
+    assert_eq!(assignments.get(&ROBOT_LEFT_HAND), Some(&Point { x: 0, y: 1 }));
+    assert_eq!(assignments.get(&ROBOT_RIGHT_HAND), Some(&Point { x: 8, y: 1 }));
+}
+
+#[test]
+fn test_auction_frontier_clusters_generalizes_beyond_two_robots() {
+    // Three participants, three clusters -- the auction isn't hard-coded to
+    // exactly robots 0 and 1.
+    let map = create_test_map(&[
+        "...........",
+        "...........",
+        "...........",
+    ]);
+
+    let robot0 = RobotNode::new(make_interior_sweep_robot_state(0, Point { x: 1, y: 1 }, 1, &map));
+    let robot1 = RobotNode::new(make_interior_sweep_robot_state(1, Point { x: 5, y: 1 }, 0, &map));
+    let robot2 = RobotNode::new(make_interior_sweep_robot_state(2, Point { x: 9, y: 1 }, 0, &map));
+    let all_robots = vec![robot0, robot1, robot2];
+
+    let pheromone = vec![0.0f32; map.cells.len()];
+    let context = PhaseContext {
+        all_robots: &all_robots,
+        global_map: &map,
+        pheromone: &pheromone,
+    };
+
+    let frontiers = vec![
+        Frontier { centroid: Point { x: 0, y: 1 }, cells: vec![Point { x: 0, y: 1 }], size: 1 },
+        Frontier { centroid: Point { x: 5, y: 0 }, cells: vec![Point { x: 5, y: 0 }], size: 1 },
+        Frontier { centroid: Point { x: 10, y: 1 }, cells: vec![Point { x: 10, y: 1 }], size: 1 },
+    ];
+
+    let assignments = context.auction_frontier_clusters(&[0, 1, 2], &frontiers);
+
+    assert_eq!(assignments.len(), 3);
+    assert_eq!(assignments.get(&0), Some(&Point { x: 0, y: 1 }));
+    assert_eq!(assignments.get(&1), Some(&Point { x: 5, y: 0 }));
+    assert_eq!(assignments.get(&2), Some(&Point { x: 10, y: 1 }));
+}
+
+#[test]
+fn test_wall_follow_step_with_repulsion_prefers_lower_pheromone_on_tie() {
+    // Open 3x3 room, robot at the center facing East. With no other robots,
+    // `repulsion_vector` is (0, 0), so every reachable neighbor ties on
+    // score and the pheromone trail alone decides the winner.
+    let map = create_test_map(&[
+        "...",
+        "...",
+        "...",
+    ]);
+
+    let current_pos = Point { x: 1, y: 1 };
+    let orientation = 0.0; // East
+
+    // Index = y * width + x, width = 3.
+    let mut pheromone = vec![1.0f32; 9];
+    pheromone[1 * 3 + 2] = 0.1; // East neighbor (2, 1): lowest trail
+
+    let next = WallFollower::wall_follow_step_with_repulsion(
+        current_pos,
+        orientation,
+        &map,
+        LEFT_HAND_RULE,
+        &[],
+        &pheromone,
+        3,
+        0.0,
+    );
+
+    assert_eq!(next, Some(Point { x: 2, y: 1 }));
+}
+
+#[test]
+fn test_wall_follow_step_with_repulsion_falls_back_to_priority_when_trails_tie() {
+    // Same setup, but with a uniform pheromone layer: the left-hand-rule
+    // priority order (turn_right, straight, turn_left, reverse) alone
+    // should decide, same as `wall_follow_step` with no repulsion/pheromone.
+    let map = create_test_map(&[
+        "...",
+        "...",
+        "...",
+    ]);
+
+    let current_pos = Point { x: 1, y: 1 };
+    let orientation = 0.0; // East
+
+    let pheromone = vec![0.0f32; 9];
+
+    let next = WallFollower::wall_follow_step_with_repulsion(
+        current_pos,
+        orientation,
+        &map,
+        LEFT_HAND_RULE,
+        &[],
+        &pheromone,
+        3,
+        0.0,
+    );
+
+    // turn_right from East is South: (1, 2).
+    assert_eq!(next, Some(Point { x: 1, y: 2 }));
 }
\ No newline at end of file
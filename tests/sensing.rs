@@ -0,0 +1,115 @@
+use multiagent_explore::robot_node::sensing::{cast_ray, cast_ray_fan, compute_visible_cells};
+use multiagent_explore::types::*;
+use multiagent_explore::constants::*;
+use std::collections::HashMap;
+
+fn create_test_map(ascii_grid: &[&str]) -> GridMap {
+    let height = ascii_grid.len();
+    let width = ascii_grid[0].len();
+    let mut map_cells = vec![CellState::Empty; width * height];
+
+    for (y, row) in ascii_grid.iter().enumerate() {
+        for (x, ch) in row.chars().enumerate() {
+            let idx = y * width + x;
+            map_cells[idx] = match ch {
+                '#' => CellState::Obstacle,
+                '.' => CellState::Empty,
+                _ => CellState::Unexplored,
+            };
+        }
+    }
+    GridMap::new(width, height, map_cells)
+}
+
+#[test]
+fn test_cast_ray_stops_at_first_obstacle() {
+    let map = create_test_map(&[
+        "..........",
+        "..........",
+        "...#......",
+        "..........",
+        "..........",
+    ]);
+
+    let visible: HashMap<Point, CellState> =
+        cast_ray(Point { x: 0, y: 2 }, 0.0, 9, &map).into_iter().collect();
+
+    assert_eq!(visible.get(&Point { x: 3, y: 2 }), Some(&CellState::Obstacle));
+    for x in 1..3 {
+        assert_eq!(visible.get(&Point { x, y: 2 }), Some(&CellState::Empty));
+    }
+    // The ray stops at the obstacle -- nothing further along the same line
+    // should be recorded.
+    assert!(!visible.contains_key(&Point { x: 4, y: 2 }));
+    assert!(!visible.contains_key(&Point { x: 5, y: 2 }));
+}
+
+#[test]
+fn test_cast_ray_travels_full_range_when_unobstructed() {
+    let map = create_test_map(&[
+        "..........",
+        "..........",
+        "..........",
+    ]);
+
+    let visible: HashMap<Point, CellState> =
+        cast_ray(Point { x: 0, y: 1 }, 0.0, 5, &map).into_iter().collect();
+
+    for x in 1..=5 {
+        assert_eq!(visible.get(&Point { x, y: 1 }), Some(&CellState::Empty), "cell ({}, 1) should be visible", x);
+    }
+    assert_eq!(visible.len(), 5);
+}
+
+#[test]
+fn test_cast_ray_fan_center_ray_matches_pose_orientation() {
+    let map = create_test_map(&[
+        "..........",
+        "..........",
+        "...#......",
+        "..........",
+        "..........",
+    ]);
+
+    let pose = Pose { position: Point { x: 0, y: 2 }, orientation_rad: 0.0 };
+    let fan_visible: HashMap<Point, CellState> = cast_ray_fan(&pose, &map).into_iter().collect();
+
+    // RAY_FAN_COUNT is odd, so its middle ray is cast at exactly
+    // `pose.orientation_rad` -- the same straight line `cast_ray` traces --
+    // and should report the same obstacle.
+    assert_eq!(RAY_FAN_COUNT % 2, 1);
+    assert_eq!(fan_visible.get(&Point { x: 3, y: 2 }), Some(&CellState::Obstacle));
+}
+
+#[test]
+fn test_compute_visible_cells_always_includes_origin() {
+    let map = create_test_map(&[
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ]);
+
+    let pose = Pose { position: Point { x: 2, y: 2 }, orientation_rad: 0.0 };
+    let visible: HashMap<Point, CellState> = compute_visible_cells(&pose, &map).into_iter().collect();
+
+    assert_eq!(visible.get(&Point { x: 2, y: 2 }), Some(&CellState::Empty));
+    assert_eq!(visible.get(&Point { x: 2, y: 1 }), Some(&CellState::Empty));
+}
+
+#[test]
+fn test_compute_visible_cells_respects_sensor_radius() {
+    let size = (4 * SENSOR_RADIUS + 20) as usize;
+    let row = ".".repeat(size);
+    let rows: Vec<&str> = std::iter::repeat(row.as_str()).take(size).collect();
+    let map = create_test_map(&rows);
+
+    let center = (size / 2) as i32;
+    let pose = Pose { position: Point { x: center, y: center }, orientation_rad: 0.0 };
+    let visible: HashMap<Point, CellState> = compute_visible_cells(&pose, &map).into_iter().collect();
+
+    // Far outside the sensor radius, straight along an axis, nothing should
+    // be revealed.
+    assert!(!visible.contains_key(&Point { x: center + SENSOR_RADIUS + 5, y: center }));
+}
@@ -21,11 +21,7 @@ fn test_robot_phase_enum() {
 
 #[test]
 fn test_grid_map_creation() {
-    let map = GridMap {
-        width: 10,
-        height: 10,
-        cells: vec![CellState::Unexplored; 100],
-    };
+    let map = GridMap::new(10, 10, vec![CellState::Unexplored; 100]);
     assert_eq!(map.cells.len(), 100);
 }
 
@@ -52,4 +48,30 @@ fn test_map_loader_ascii_grid() {
     assert_eq!(map.cells[6], CellState::Obstacle);
     assert_eq!(map.cells[7], CellState::Obstacle);
     assert_eq!(map.cells[8], CellState::Obstacle);
+}
+
+#[test]
+fn test_compute_wavefront_distance_from_source() {
+    // 5x1 open corridor, source at the left end -- distance should increase
+    // by exactly one per cell moving right.
+    let map = GridMap::new(5, 1, vec![CellState::Empty; 5]);
+    let wavefront = map.compute_wavefront(&[Point { x: 0, y: 0 }]);
+    assert_eq!(wavefront, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_compute_wavefront_blocked_by_obstacle() {
+    // Obstacle splits a 3x1 corridor in two -- the far cell is unreachable
+    // from the near source.
+    let map = GridMap::new(3, 1, vec![CellState::Empty, CellState::Obstacle, CellState::Empty]);
+    let wavefront = map.compute_wavefront(&[Point { x: 0, y: 0 }]);
+    assert_eq!(wavefront[0], 0);
+    assert_eq!(wavefront[2], i32::MAX);
+}
+
+#[test]
+fn test_compute_wavefront_multiple_sources_takes_nearest() {
+    let map = GridMap::new(5, 1, vec![CellState::Empty; 5]);
+    let wavefront = map.compute_wavefront(&[Point { x: 0, y: 0 }, Point { x: 4, y: 0 }]);
+    assert_eq!(wavefront, vec![0, 1, 2, 1, 0]);
 } 
\ No newline at end of file